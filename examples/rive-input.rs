@@ -4,8 +4,7 @@
 use bevy::{prelude::*, render::render_resource::Extent3d, window};
 use rive_bevy::{
     events::{self, InputValue},
-    rive_rs::components::TextValueRun,
-    RivePlugin, RiveStateMachine, SceneTarget, SpriteEntity, StateMachine,
+    RiveApplySet, RiveInputValues, RivePlugin, RiveText, SceneTarget, SpriteEntity, StateMachine,
 };
 
 const BACKGROUND_COLOR: Color = Color::rgb(0.0, 0.0, 0.0);
@@ -19,7 +18,7 @@ fn main() {
         .add_systems(Update, window::close_on_esc)
         .add_systems(
             Update,
-            (update_state_machine_system, update_rive_text_system),
+            (update_state_machine_system, update_rive_text_system).before(RiveApplySet),
         )
         .run()
 }
@@ -107,15 +106,15 @@ fn setup_text(mut commands: Commands) {
 
 fn update_state_machine_system(
     kbd: Res<Input<KeyCode>>,
-    mut query: Query<(Entity, &mut RiveStateMachine)>,
+    query: Query<(Entity, &RiveInputValues)>,
     mut input_events: EventWriter<events::Input>,
 ) {
     if kbd.just_pressed(KeyCode::Return) {
         // Get the State Machine and its Entity
-        let (entity, state_machine) = query.single_mut();
+        let (entity, input_values) = query.single();
 
-        // Read the current value of an input
-        let center_hover_current = state_machine.get_bool("centerHover").unwrap().get();
+        // Read the current value of an input, as mirrored after the last frame
+        let center_hover_current = input_values.get_bool("centerHover").unwrap_or_default();
 
         // Send a new value to the input using Bevy events.
         {
@@ -127,7 +126,7 @@ fn update_state_machine_system(
         }
 
         // Alternatively we can use the raw API and send the value directly to the Rive C++ API.
-        // Comment the above Bevy event and uncomment the below.
+        // Comment the above Bevy event, query `&RiveStateMachine` and uncomment the below.
         {
             // state_machine
             //     .get_bool("centerHover")
@@ -139,7 +138,7 @@ fn update_state_machine_system(
 
 fn update_rive_text_system(
     kbd: Res<Input<KeyCode>>,
-    mut query: Query<&mut RiveStateMachine>,
+    mut query: Query<(&RiveInputValues, &mut RiveText)>,
     mut string: Local<String>,
     mut evr_char: EventReader<ReceivedCharacter>,
 ) {
@@ -165,24 +164,16 @@ fn update_rive_text_system(
 
     // Update our Rive text if the string changed.
     if did_change {
-        let state_machine = query.single_mut();
+        let (input_values, mut text) = query.single_mut();
 
-        if !state_machine.get_bool("centerHover").unwrap().get() {
+        if !input_values.get_bool("centerHover").unwrap_or_default() {
             return;
         }
 
-        let mut artboard = state_machine.artboard();
-
-        let mut text: TextValueRun = artboard
-            .components()
-            .find(|comp| comp.name() == "Sector")
-            .unwrap()
-            .try_into()
-            .unwrap();
-
         let mut formatted_value: String = string.to_owned();
         formatted_value.push_str(" : ");
 
-        text.set_text(&formatted_value);
+        // The text is applied before the state machine is advanced this frame.
+        text.set("Sector", formatted_value).unwrap();
     }
 }
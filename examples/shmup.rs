@@ -13,8 +13,8 @@ use bevy::{
 use rand::prelude::*;
 
 use rive_bevy::{
-    events, rive_rs::scene::Scene, Riv, RivePlugin, RiveStateMachine, SceneTarget, SpriteEntity,
-    StateMachine,
+    events, rive_rs::scene::Scene, InputBinding, Riv, RiveInputBindings, RivePlugin,
+    RiveStateMachine, SceneTarget, SpriteEntity, StateMachine,
 };
 
 // const BACKGROUND_COLOR: Color = Color::rgb(0.0, 0.0, 0.0);
@@ -59,6 +59,7 @@ fn main() {
         }))
         .add_plugins(RivePlugin)
         .init_resource::<EnemyMoveTimer>()
+        .register_type::<Player>()
         .insert_resource(ClearColor(BACKGROUND_COLOR))
         .add_systems(Startup, setup)
         .add_systems(Update, window::close_on_esc)
@@ -95,7 +96,8 @@ struct TargetPosition {
     position: Vec2,
 }
 
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 struct Player {
     drift: f32,
     target_drift: f32,
@@ -233,6 +235,8 @@ fn setup(
                     size: PLAYER_COLIDER_SIZE,
                 },
                 sm,
+                // Keep the state machine's drift input in sync with the player.
+                RiveInputBindings::default().with(InputBinding::new("Player.drift", "drift")),
             ))
             .id();
 
@@ -664,24 +668,14 @@ fn move_to_target_position_system(
     }
 }
 
-fn drift_player_ship_system(
-    mut query: Query<(Entity, &mut Player)>,
-    mut input_events: EventWriter<events::Input>,
-) {
-    let (entity, mut player) = query.single_mut();
+fn drift_player_ship_system(mut query: Query<&mut Player>) {
+    let mut player = query.single_mut();
 
     let mut current_drift = lerp(player.drift, player.target_drift, 0.1);
 
     current_drift = current_drift.clamp(-100.0, 100.0);
 
     player.drift = current_drift;
-
-    // Send Rive input event to update the state machine's drift input.
-    input_events.send(events::Input {
-        state_machine: entity,
-        name: "drift".into(),
-        value: events::InputValue::Number(player.drift),
-    });
 }
 
 fn despawn_dead_enemies_system(
@@ -0,0 +1,47 @@
+//! An example displaying a Rive State Machine as a `bevy_ui` node - with mouse inputs.
+
+use bevy::{prelude::*, window};
+use rive_bevy::{RivePlugin, RiveUiBundle, StateMachine};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(RivePlugin)
+        .add_systems(Startup, setup_ui)
+        .add_systems(Update, window::close_on_esc)
+        .run()
+}
+
+fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            // The target image is created and resized by the plugin to match the node.
+            parent.spawn(RiveUiBundle {
+                state_machine: StateMachine {
+                    riv: asset_server.load("rating-animation.riv"),
+                    ..default()
+                },
+                node: NodeBundle {
+                    style: Style {
+                        width: Val::Percent(50.0),
+                        aspect_ratio: Some(1.0),
+                        ..default()
+                    },
+                    ..RiveUiBundle::default().node
+                },
+                ..default()
+            });
+        });
+}
@@ -4,13 +4,78 @@ use bevy::{
     reflect::TypePath,
     utils::{
         thiserror::{self, Error},
-        BoxedFuture,
+        BoxedFuture, HashMap, HashSet,
     },
 };
 use rive_rs::File;
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::RivCatalog;
 
 #[derive(Asset, Debug, Deref, TypePath)]
-pub struct Riv(pub rive_rs::File);
+pub struct Riv {
+    #[deref]
+    file: rive_rs::File,
+    catalog: RivCatalog,
+    default_artboard: Option<String>,
+}
+
+/// An artboard of a [`Riv`], loaded with `asset_server.load("file.riv#Artboard")`.
+#[derive(Asset, Clone, Debug, TypePath)]
+pub struct RivArtboard {
+    pub name: String,
+    pub linear_animations: Vec<Handle<RivLinearAnimation>>,
+    pub state_machines: Vec<Handle<RivStateMachine>>,
+}
+
+/// A linear animation of a [`Riv`], loaded with
+/// `asset_server.load("file.riv#Artboard/LinearAnimation")`.
+///
+/// Spawn the handle on an entity to play the animation, like a
+/// [`LinearAnimation`](crate::LinearAnimation) would.
+#[derive(Asset, Clone, Debug, TypePath)]
+pub struct RivLinearAnimation {
+    pub artboard: String,
+    pub name: String,
+}
+
+/// A state machine of a [`Riv`], loaded with
+/// `asset_server.load("file.riv#Artboard/StateMachine")`.
+///
+/// Spawn the handle on an entity to run the state machine, like a
+/// [`StateMachine`](crate::StateMachine) would.
+#[derive(Asset, Clone, Debug, TypePath)]
+pub struct RivStateMachine {
+    pub artboard: String,
+    pub name: String,
+}
+
+impl Riv {
+    pub fn new(file: rive_rs::File) -> Self {
+        let catalog = RivCatalog::new(&file);
+
+        Self {
+            file,
+            catalog,
+            default_artboard: None,
+        }
+    }
+
+    /// Returns the artboards, animations, state machines and inputs
+    /// contained in the file.
+    pub fn catalog(&self) -> &RivCatalog {
+        &self.catalog
+    }
+
+    /// Resolves [`rive_rs::Handle::Default`] to the artboard configured in
+    /// [`RivLoaderSettings::default_artboard`], if any.
+    pub(crate) fn artboard_handle(&self, handle: &rive_rs::Handle) -> rive_rs::Handle {
+        match (handle, &self.default_artboard) {
+            (rive_rs::Handle::Default, Some(name)) => rive_rs::Handle::Name(name.clone().into()),
+            _ => handle.clone(),
+        }
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum RivLoaderError {
@@ -20,6 +85,217 @@ pub enum RivLoaderError {
     /// A [RON](ron) Error
     #[error("Could not read Riv: {0}")]
     RivError(#[from] rive_rs::Error),
+    /// The artboard set in [`RivLoaderSettings::default_artboard`] does not exist.
+    #[error("default artboard {0:?} cannot be found")]
+    MissingDefaultArtboard(String),
+    /// An out-of-band asset path could not be built from the settings.
+    #[error("invalid path for referenced asset {0:?}")]
+    InvalidAssetPath(String),
+    /// A state machine failed [`RivLoaderSettings::validate_state_machines`].
+    #[error("state machine {state_machine:?} in artboard {artboard:?} is invalid: {reason}")]
+    InvalidStateMachine {
+        artboard: String,
+        state_machine: String,
+        reason: String,
+    },
+}
+
+/// Kinds of assets embedded in a `.riv`, see
+/// [`RivLoaderSettings::embedded_assets`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RivEmbeddedAssets {
+    pub images: bool,
+    pub fonts: bool,
+}
+
+impl Default for RivEmbeddedAssets {
+    fn default() -> Self {
+        Self {
+            images: true,
+            fonts: true,
+        }
+    }
+}
+
+impl RivEmbeddedAssets {
+    /// Returns whether the asset called `filename` gets decoded, based on the
+    /// extension Rive gives each kind of asset.
+    fn decodes(&self, filename: &str) -> bool {
+        match filename.rsplit_once('.').map(|(_, extension)| extension) {
+            Some("png" | "jpeg" | "jpg" | "webp") => self.images,
+            Some("ttf" | "otf") => self.fonts,
+            _ => true,
+        }
+    }
+}
+
+/// Settings for [`RivLoader`], configurable per asset in `.riv.meta` files.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RivLoaderSettings {
+    /// Artboard instantiated for scenes whose `artboard_handle` is
+    /// [`rive_rs::Handle::Default`], instead of the file's default one.
+    pub default_artboard: Option<String>,
+    /// Fails the load if a state machine cannot be driven by name, i.e. it has
+    /// unnamed inputs or several inputs with the same name.
+    pub validate_state_machines: bool,
+    /// Folder where images and fonts that are referenced by the file but not
+    /// embedded in it are looked up, relative to the `.riv`. Paths starting
+    /// with `/` are relative to the asset source's root instead, which allows
+    /// pointing each platform's `.meta` to its own folder.
+    ///
+    /// Defaults to the `.riv`'s own folder.
+    pub assets_dir: Option<String>,
+    /// Kinds of embedded assets that are decoded. Skipped ones are never
+    /// drawn, e.g. to save memory on images that a platform does not show.
+    /// Referenced assets are always decoded.
+    pub embedded_assets: RivEmbeddedAssets,
+}
+
+/// Reads the images and fonts that `bytes` references but does not embed.
+///
+/// They are read through `load_context` so that they are tracked as
+/// dependencies and modifying them reloads the `.riv`. Missing assets are
+/// skipped; Rive then draws the file without them.
+async fn read_referenced_assets(
+    bytes: &[u8],
+    settings: &RivLoaderSettings,
+    load_context: &mut LoadContext<'_>,
+) -> Result<HashMap<String, Vec<u8>>, RivLoaderError> {
+    // Parse the file once just to find out which assets it references.
+    let mut filenames = Vec::new();
+    File::new_with_assets(bytes, |asset| {
+        if !asset.is_embedded() {
+            filenames.push(asset.unique_filename());
+        }
+
+        None
+    })?;
+
+    let mut assets = HashMap::new();
+    for filename in filenames {
+        let relative = match &settings.assets_dir {
+            Some(dir) => format!("{}/{filename}", dir.trim_end_matches('/')),
+            None => filename.clone(),
+        };
+        let path = load_context
+            .asset_path()
+            .resolve_embed(&relative)
+            .map_err(|_| RivLoaderError::InvalidAssetPath(relative))?;
+
+        match load_context.read_asset_bytes(path.clone()).await {
+            Ok(bytes) => {
+                assets.insert(filename, bytes);
+            }
+            Err(error) => warn!("referenced asset {path:?} cannot be read: {error}"),
+        }
+    }
+
+    Ok(assets)
+}
+
+fn validate_state_machines(catalog: &RivCatalog) -> Result<(), RivLoaderError> {
+    for artboard in &catalog.artboards {
+        for state_machine in &artboard.state_machines {
+            let invalid = |reason: String| RivLoaderError::InvalidStateMachine {
+                artboard: artboard.name.clone(),
+                state_machine: state_machine.name.clone(),
+                reason,
+            };
+            let mut names = HashSet::new();
+
+            for input in &state_machine.inputs {
+                if input.name.is_empty() {
+                    return Err(invalid("an input has no name".to_string()));
+                }
+
+                if !names.insert(input.name.as_str()) {
+                    return Err(invalid(format!("input {:?} is duplicated", input.name)));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Registers `Artboard` and `Artboard/Scene` labels for every artboard, linear
+/// animation and state machine. State machines win over linear animations
+/// with the same name.
+fn add_labeled_assets(riv: &Riv, load_context: &mut LoadContext) {
+    let mut labels = HashSet::new();
+
+    for artboard in &riv.catalog().artboards {
+        if !labels.insert(artboard.name.clone()) {
+            warn!(
+                "artboard {:?} in {:?} has a duplicate name and cannot be loaded by label",
+                artboard.name,
+                load_context.path(),
+            );
+
+            continue;
+        }
+
+        let mut label = |name: &str| {
+            let label = format!("{}/{name}", artboard.name);
+            let is_new = labels.insert(label.clone());
+
+            if !is_new {
+                warn!(
+                    "{label:?} in {:?} is ambiguous; only the first scene with this name can be \
+                     loaded by label",
+                    load_context.path(),
+                );
+            }
+
+            is_new.then_some(label)
+        };
+
+        let state_machines: Vec<_> = artboard
+            .state_machines
+            .iter()
+            .filter_map(|state_machine| Some((label(&state_machine.name)?, state_machine)))
+            .collect();
+        let linear_animations: Vec<_> = artboard
+            .linear_animations
+            .iter()
+            .filter_map(|linear_animation| Some((label(&linear_animation.name)?, linear_animation)))
+            .collect();
+
+        let state_machines = state_machines
+            .into_iter()
+            .map(|(label, state_machine)| {
+                load_context.add_labeled_asset(
+                    label,
+                    RivStateMachine {
+                        artboard: artboard.name.clone(),
+                        name: state_machine.name.clone(),
+                    },
+                )
+            })
+            .collect();
+        let linear_animations = linear_animations
+            .into_iter()
+            .map(|(label, linear_animation)| {
+                load_context.add_labeled_asset(
+                    label,
+                    RivLinearAnimation {
+                        artboard: artboard.name.clone(),
+                        name: linear_animation.name.clone(),
+                    },
+                )
+            })
+            .collect();
+
+        load_context.add_labeled_asset(
+            artboard.name.clone(),
+            RivArtboard {
+                name: artboard.name.clone(),
+                linear_animations,
+                state_machines,
+            },
+        );
+    }
 }
 
 #[derive(Default)]
@@ -27,20 +303,47 @@ pub struct RivLoader;
 
 impl AssetLoader for RivLoader {
     type Asset = Riv;
-    type Settings = ();
+    type Settings = RivLoaderSettings;
     type Error = RivLoaderError;
 
     fn load<'a>(
         &'a self,
         reader: &'a mut Reader,
-        _settings: &'a Self::Settings,
-        _load_context: &'a mut LoadContext,
+        settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
         Box::pin(async move {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
 
-            Ok(Riv(File::new(&bytes)?))
+            let referenced = read_referenced_assets(&bytes, settings, load_context).await?;
+            let file = File::new_with_assets(&bytes, |asset| {
+                let filename = asset.unique_filename();
+
+                if asset.is_embedded() {
+                    // Empty contents keep Rive from decoding the embedded ones.
+                    return (!settings.embedded_assets.decodes(&filename)).then(Vec::new);
+                }
+
+                referenced.get(&filename).cloned()
+            })?;
+            let mut riv = Riv::new(file);
+
+            if let Some(name) = &settings.default_artboard {
+                if riv.catalog.artboard(name).is_none() {
+                    return Err(RivLoaderError::MissingDefaultArtboard(name.clone()));
+                }
+
+                riv.default_artboard = Some(name.clone());
+            }
+
+            if settings.validate_state_machines {
+                validate_state_machines(&riv.catalog)?;
+            }
+
+            add_labeled_assets(&riv, load_context);
+
+            Ok(riv)
         })
     }
 
@@ -0,0 +1,280 @@
+use bevy::{
+    ecs::{
+        reflect::{ReflectComponent, ReflectResource},
+        world::EntityWorldMut,
+    },
+    prelude::*,
+    reflect::GetPath,
+};
+
+use crate::{
+    catalog::InputKind,
+    inputs::{RiveInputValues, RiveInputs},
+};
+
+/// Keeps a state machine input in sync with a reflected field.
+///
+/// The path starts with the short type name of a component or resource,
+/// followed by a field path, e.g. `Health.current` or `Player.stats[0].speed`.
+/// Components are read from the state machine's own entity unless another
+/// one is given with [`InputBinding::on`]. The type must be registered and
+/// reflect `Component` or `Resource`.
+///
+/// Number inputs take numeric fields multiplied by the scale, or bool fields
+/// as `0.0`/`1.0`. Bool inputs take bool fields, or numeric fields compared
+/// against the threshold.
+#[derive(Clone, Debug)]
+pub struct InputBinding {
+    path: String,
+    input: String,
+    entity: Option<Entity>,
+    scale: f32,
+    threshold: f32,
+    reported: bool,
+}
+
+impl InputBinding {
+    pub fn new(path: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            input: input.into(),
+            entity: None,
+            scale: 1.0,
+            threshold: 0.5,
+            reported: false,
+        }
+    }
+
+    /// Reads the component from `entity` instead of the state machine's.
+    pub fn on(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+
+    /// Multiplies numeric values bound to number inputs by `scale`.
+    pub fn scaled(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets bool inputs when numeric values are at least `threshold`.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// Inputs of the entity's state machine that are driven by reflected fields,
+/// synced every frame before the state machine advances.
+#[derive(Clone, Component, Debug, Default)]
+pub struct RiveInputBindings(pub Vec<InputBinding>);
+
+impl RiveInputBindings {
+    pub fn with(mut self, binding: InputBinding) -> Self {
+        self.0.push(binding);
+        self
+    }
+}
+
+impl BindingSet for RiveInputBindings {
+    type Binding = InputBinding;
+
+    fn bindings_mut(&mut self) -> &mut [InputBinding] {
+        &mut self.0
+    }
+
+    fn path(binding: &InputBinding) -> &str {
+        &binding.path
+    }
+
+    fn reported(binding: &mut InputBinding) -> &mut bool {
+        &mut binding.reported
+    }
+}
+
+enum Raw {
+    Bool(bool),
+    Number(f32),
+}
+
+fn to_raw(value: &dyn Reflect) -> Option<Raw> {
+    let any = value.as_any();
+
+    if let Some(val) = any.downcast_ref::<bool>() {
+        return Some(Raw::Bool(*val));
+    }
+
+    macro_rules! numbers {
+        ( $( $ty:ty ),* ) => {
+            $(
+                if let Some(val) = any.downcast_ref::<$ty>() {
+                    return Some(Raw::Number(*val as f32));
+                }
+            )*
+        };
+    }
+
+    numbers!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+    None
+}
+
+/// Reads the field at `path`, as described on [`InputBinding`], and converts it
+/// with `convert`, which returns `None` if the field has the wrong type.
+pub(crate) fn read_field<T>(
+    world: &World,
+    entity: Entity,
+    path: &str,
+    convert: impl FnOnce(&dyn Reflect) -> Option<T>,
+    expected: &str,
+) -> Result<T, String> {
+    let (type_name, field) = path.split_once('.').unwrap_or((path, ""));
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let registration = registry
+        .get_with_short_type_path(type_name)
+        .ok_or_else(|| format!("type {type_name:?} is not registered"))?;
+
+    let value = if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+        world
+            .get_entity(entity)
+            .and_then(|entity| reflect_component.reflect(entity))
+            .ok_or_else(|| format!("{entity:?} has no {type_name} component"))?
+    } else if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+        reflect_resource
+            .reflect(world)
+            .ok_or_else(|| format!("resource {type_name} does not exist"))?
+    } else {
+        return Err(format!(
+            "{type_name} reflects neither Component nor Resource"
+        ));
+    };
+
+    let value = if field.is_empty() {
+        value
+    } else {
+        value
+            .reflect_path(field)
+            .map_err(|error| format!("invalid path {path:?}: {error}"))?
+    };
+
+    convert(value).ok_or_else(|| format!("{path:?} is not {expected}"))
+}
+
+/// A component of bindings kept in sync by [`sync_bindings`].
+pub(crate) trait BindingSet: Component + Clone {
+    type Binding;
+
+    fn bindings_mut(&mut self) -> &mut [Self::Binding];
+
+    fn path(binding: &Self::Binding) -> &str;
+
+    /// Whether the binding's current error was logged already.
+    fn reported(binding: &mut Self::Binding) -> &mut bool;
+}
+
+/// Evaluates the bindings of every entity with both `B` and `T`, and hands
+/// the values that changed to `write`.
+///
+/// `value` returns `None` for bindings whose value is already up to date.
+/// Errors are only logged when a binding starts failing, not every frame.
+pub(crate) fn sync_bindings<B: BindingSet, T: Component, V>(
+    world: &mut World,
+    value: impl Fn(&World, Entity, &B::Binding) -> Result<Option<V>, String>,
+    write: impl Fn(&mut EntityWorldMut, Vec<V>),
+) {
+    let mut query = world.query_filtered::<(Entity, &B), With<T>>();
+    let bound: Vec<_> = query
+        .iter(world)
+        .map(|(entity, bindings)| (entity, bindings.clone()))
+        .collect();
+
+    for (entity, mut bindings) in bound {
+        let mut values = Vec::new();
+
+        for binding in bindings.bindings_mut() {
+            match value(world, entity, binding) {
+                Ok(val) => {
+                    *B::reported(binding) = false;
+                    values.extend(val);
+                }
+                Err(error) => {
+                    if !*B::reported(binding) {
+                        error!("binding {:?} on {entity:?}: {error}", B::path(binding));
+                        *B::reported(binding) = true;
+                    }
+                }
+            }
+        }
+
+        let mut entity_mut = world.entity_mut(entity);
+        write(&mut entity_mut, values);
+
+        if let Some(mut current) = entity_mut.get_mut::<B>() {
+            // Bookkeeping only; users should not see the bindings as changed.
+            let current = current.bypass_change_detection().bindings_mut();
+            for (current, binding) in current.iter_mut().zip(bindings.bindings_mut()) {
+                *B::reported(current) = *B::reported(binding);
+            }
+        }
+    }
+}
+
+impl InputBinding {
+    fn value(&self, world: &World, entity: Entity) -> Result<InputKind, String> {
+        let inputs = world
+            .get::<RiveInputs>(entity)
+            .ok_or_else(|| "the state machine is not instantiated".to_string())?;
+        let input = inputs
+            .declared()
+            .iter()
+            .find(|input| input.name == self.input)
+            .ok_or_else(|| format!("input {:?} cannot be found", self.input))?;
+
+        let raw = read_field(
+            world,
+            self.entity.unwrap_or(entity),
+            &self.path,
+            to_raw,
+            "a bool or a number",
+        )?;
+
+        match (input.kind, raw) {
+            (InputKind::Bool(_), Raw::Bool(val)) => Ok(InputKind::Bool(val)),
+            (InputKind::Bool(_), Raw::Number(val)) => Ok(InputKind::Bool(val >= self.threshold)),
+            (InputKind::Number(_), Raw::Bool(val)) => {
+                Ok(InputKind::Number(if val { 1.0 } else { 0.0 }))
+            }
+            (InputKind::Number(_), Raw::Number(val)) => Ok(InputKind::Number(val * self.scale)),
+            (InputKind::Trigger, _) => Err(format!("trigger {:?} cannot be bound", self.input)),
+        }
+    }
+}
+
+pub(crate) fn sync_input_bindings(world: &mut World) {
+    sync_bindings::<RiveInputBindings, RiveInputs, _>(
+        world,
+        |world, entity, binding| {
+            let value = binding.value(world, entity)?;
+            let current = world
+                .get::<RiveInputValues>(entity)
+                .and_then(|current| current.get(&binding.input));
+
+            Ok((current != Some(value)).then(|| (binding.input.clone(), value)))
+        },
+        |entity, values| {
+            let Some(mut inputs) = entity.get_mut::<RiveInputs>() else {
+                return;
+            };
+
+            for (name, value) in values {
+                // Names and types were checked against the declared inputs.
+                let _ = match value {
+                    InputKind::Bool(val) => inputs.set_bool(&name, val),
+                    InputKind::Number(val) => inputs.set_number(&name, val),
+                    InputKind::Trigger => Ok(()),
+                };
+            }
+        },
+    );
+}
@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rive_rs::{components::Event, state_machine::Input, Instantiate, Scene};
+
+use crate::text::text_runs;
+
+/// The kind of a state machine input, along with its default value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputKind {
+    Bool(bool),
+    Number(f32),
+    Trigger,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputInfo {
+    pub name: String,
+    pub kind: InputKind,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinearAnimationInfo {
+    pub index: usize,
+    pub name: String,
+    /// `None` for animations that have no end, e.g. ones with a work area of
+    /// zero length.
+    pub duration: Option<Duration>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StateMachineInfo {
+    pub index: usize,
+    pub name: String,
+    pub inputs: Vec<InputInfo>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TextRunInfo {
+    /// Name of the run, prefixed by the names of the nested artboards it is
+    /// in, e.g. `Nested/Run`, as used by [`RiveText`](crate::RiveText).
+    pub path: String,
+    /// The text the run starts with.
+    pub text: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArtboardInfo {
+    pub index: usize,
+    pub name: String,
+    /// Size of the artboard, in artboard units.
+    pub size: Vec2,
+    pub linear_animations: Vec<LinearAnimationInfo>,
+    pub state_machines: Vec<StateMachineInfo>,
+    /// Names of the events the artboard's state machines can report.
+    pub events: Vec<String>,
+    /// Text runs, sorted by path.
+    pub text_runs: Vec<TextRunInfo>,
+}
+
+/// Everything that can be instantiated from a [`Riv`](crate::Riv), computed
+/// once when the file is loaded.
+#[derive(Clone, Debug, Default)]
+pub struct RivCatalog {
+    pub artboards: Vec<ArtboardInfo>,
+}
+
+fn instances<T: Instantiate>(from: &T::From) -> impl Iterator<Item = (usize, T)> + '_ {
+    (0..).map_while(|i| Some((i, T::instantiate(from, rive_rs::Handle::Index(i))?)))
+}
+
+/// Describes the inputs of `state_machine`, with their current values.
+pub(crate) fn input_infos(state_machine: &rive_rs::StateMachine) -> Vec<InputInfo> {
+    state_machine
+        .inputs()
+        .map(|input| match input {
+            Input::Bool(input) => InputInfo {
+                name: input.name().to_string(),
+                kind: InputKind::Bool(input.get()),
+            },
+            Input::Number(input) => InputInfo {
+                name: input.name().to_string(),
+                kind: InputKind::Number(input.get()),
+            },
+            Input::Trigger(input) => InputInfo {
+                name: input.name().to_string(),
+                kind: InputKind::Trigger,
+            },
+        })
+        .collect()
+}
+
+fn text_run_infos(artboard: &mut rive_rs::Artboard) -> Vec<TextRunInfo> {
+    let mut text_runs: Vec<_> = text_runs(artboard)
+        .into_iter()
+        .map(|(path, text)| TextRunInfo { path, text })
+        .collect();
+    text_runs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    text_runs
+}
+
+fn event_names(artboard: &mut rive_rs::Artboard) -> Vec<String> {
+    artboard
+        .components()
+        .filter_map(|component| Event::try_from(component).ok())
+        .map(|event| event.name().to_string())
+        .collect()
+}
+
+impl RivCatalog {
+    pub(crate) fn new(file: &rive_rs::File) -> Self {
+        let artboards = instances::<rive_rs::Artboard>(file)
+            .map(|(index, mut artboard)| ArtboardInfo {
+                index,
+                name: artboard.name().to_string(),
+                size: Vec2::new(artboard.width(), artboard.height()),
+                linear_animations: instances::<rive_rs::LinearAnimation>(&artboard)
+                    .map(|(index, linear_animation)| LinearAnimationInfo {
+                        index,
+                        name: linear_animation.name().to_string(),
+                        duration: linear_animation.duration(),
+                    })
+                    .collect(),
+                state_machines: instances::<rive_rs::StateMachine>(&artboard)
+                    .map(|(index, state_machine)| StateMachineInfo {
+                        index,
+                        name: state_machine.name().to_string(),
+                        inputs: input_infos(&state_machine),
+                    })
+                    .collect(),
+                events: event_names(&mut artboard),
+                text_runs: text_run_infos(&mut artboard),
+            })
+            .collect();
+
+        Self { artboards }
+    }
+
+    /// Returns the artboard called `name`.
+    pub fn artboard(&self, name: &str) -> Option<&ArtboardInfo> {
+        self.artboards.iter().find(|artboard| artboard.name == name)
+    }
+
+    pub fn artboard_names(&self) -> impl Iterator<Item = &str> {
+        self.artboards.iter().map(|artboard| artboard.name.as_str())
+    }
+}
+
+impl ArtboardInfo {
+    /// Returns the linear animation called `name`.
+    pub fn linear_animation(&self, name: &str) -> Option<&LinearAnimationInfo> {
+        self.linear_animations
+            .iter()
+            .find(|linear_animation| linear_animation.name == name)
+    }
+
+    /// Returns the state machine called `name`.
+    pub fn state_machine(&self, name: &str) -> Option<&StateMachineInfo> {
+        self.state_machines
+            .iter()
+            .find(|state_machine| state_machine.name == name)
+    }
+
+    /// Returns the text run at `path`.
+    pub fn text_run(&self, path: &str) -> Option<&TextRunInfo> {
+        self.text_runs.iter().find(|text_run| text_run.path == path)
+    }
+}
+
+impl StateMachineInfo {
+    /// Returns the input called `name`.
+    pub fn input(&self, name: &str) -> Option<&InputInfo> {
+        self.inputs.iter().find(|input| input.name == name)
+    }
+}
@@ -1,9 +1,19 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use bevy::{prelude::*, render::extract_component::ExtractComponent};
-use vello::SceneFragment;
+use bevy::{
+    math::Affine2,
+    prelude::*,
+    render::{
+        extract_component::ExtractComponent,
+        render_resource::{Extent3d, Face, TextureDimension, TextureFormat, TextureUsages},
+    },
+};
+use vello::{kurbo::Affine, SceneFragment};
 
-use crate::Riv;
+use crate::{
+    events::{InputValue, RiveErrorKind},
+    Riv,
+};
 
 #[derive(Clone, Component, Debug, Default)]
 pub struct LinearAnimation {
@@ -16,6 +26,78 @@ pub struct LinearAnimation {
 #[derive(Component, Debug, Deref, DerefMut)]
 pub struct RiveLinearAnimation(pub rive_rs::LinearAnimation);
 
+impl RiveLinearAnimation {
+    /// Current time of the animation, from its start.
+    pub fn time(&self) -> Duration {
+        Duration::from_secs_f32(self.0.time().max(0.0))
+    }
+
+    /// Length of the animation, or `None` if it does not end.
+    pub fn duration(&self) -> Option<Duration> {
+        rive_rs::Scene::duration(&self.0)
+    }
+
+    /// Jumps to `time`; the scene is redrawn on its next advance.
+    pub fn seek(&mut self, time: Duration) {
+        self.0.set_time(time.as_secs_f32());
+    }
+
+    /// Jumps to `frame`, counted at the animation's own frame rate.
+    pub fn seek_frame(&mut self, frame: u32) {
+        self.0.set_time(frame as f32 / self.0.fps() as f32);
+    }
+
+    pub fn set_loop(&mut self, loop_: rive_rs::Loop) {
+        self.0.set_loop(loop_);
+    }
+
+    /// Whether time runs backwards, from the end towards the start.
+    pub fn is_reversed(&self) -> bool {
+        self.0.is_reverse_playing()
+    }
+
+    pub fn set_reversed(&mut self, reversed: bool) {
+        self.0.set_is_reverse_playing(reversed);
+    }
+
+    pub(crate) fn progress(&self) -> (f32, bool) {
+        (self.0.time(), self.is_reversed())
+    }
+
+    /// Returns whether advancing from the `before` progress to the current one
+    /// finished or looped the animation, as `(finished, looped)`.
+    pub(crate) fn progress_since(&self, before: (f32, bool)) -> (bool, bool) {
+        let duration = self.duration().map(|duration| duration.as_secs_f32());
+
+        progress_between(before, self.progress(), duration)
+    }
+}
+
+/// Returns whether going from the `before` to the `after` `(time, reversed)`
+/// progress finished or looped an animation lasting `duration` seconds.
+fn progress_between(
+    (time_before, reversed_before): (f32, bool),
+    (time, reversed): (f32, bool),
+    duration: Option<f32>,
+) -> (bool, bool) {
+    // Ping-pong animations change direction instead of wrapping around.
+    let looped = reversed != reversed_before
+        || if reversed {
+            time > time_before
+        } else {
+            time < time_before
+        };
+
+    let at_end = if reversed {
+        time <= 0.0
+    } else {
+        duration.is_some_and(|duration| time >= duration)
+    };
+    let finished = !looped && at_end && time != time_before;
+
+    (finished, looped)
+}
+
 #[derive(Clone, Component, Debug, Default)]
 pub struct StateMachine {
     pub riv: Handle<Riv>,
@@ -27,23 +109,314 @@ pub struct StateMachine {
 #[derive(Component, Debug, Deref, DerefMut)]
 pub struct RiveStateMachine(pub rive_rs::StateMachine);
 
-#[derive(Component, Debug)]
-pub(crate) struct MissingArtboard;
+/// Where a scene is in its instantiation.
+///
+/// Inserted and kept up to date by the plugin on every entity with a
+/// [`LinearAnimation`] or a [`StateMachine`].
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RiveSceneStatus {
+    /// The `.riv` is not loaded yet.
+    #[default]
+    Loading,
+    /// The scene is instantiated and being rendered.
+    Ready,
+    /// The scene could not be instantiated; a [`RiveError`](crate::RiveError) was sent.
+    Failed(RiveErrorKind),
+}
+
+/// State carried over when a scene is rebuilt after its `.riv` was modified.
+#[derive(Component, Debug, Default)]
+pub(crate) struct ReloadState {
+    pub inputs: Vec<(String, InputValue)>,
+    pub time: Option<f32>,
+}
+
+impl ReloadState {
+    pub fn capture(
+        linear_animation: Option<&RiveLinearAnimation>,
+        state_machine: Option<&RiveStateMachine>,
+    ) -> Self {
+        let inputs = state_machine
+            .map(|state_machine| {
+                state_machine
+                    .inputs()
+                    .filter_map(|input| match input {
+                        rive_rs::state_machine::Input::Bool(input) => {
+                            Some((input.name().to_string(), InputValue::Bool(input.get())))
+                        }
+                        rive_rs::state_machine::Input::Number(input) => {
+                            Some((input.name().to_string(), InputValue::Number(input.get())))
+                        }
+                        // Triggers only last for a single advance.
+                        rive_rs::state_machine::Input::Trigger(_) => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            inputs,
+            time: linear_animation.map(|linear_animation| linear_animation.0.time()),
+        }
+    }
+
+    pub fn restore_linear_animation(&self, linear_animation: &mut rive_rs::LinearAnimation) {
+        if let Some(time) = self.time {
+            linear_animation.set_time(time);
+        }
+    }
+
+    pub fn restore_state_machine(&self, state_machine: &rive_rs::StateMachine) {
+        for (name, value) in &self.inputs {
+            // Inputs that were renamed or removed in the new file are dropped.
+            match value {
+                InputValue::Bool(val) => {
+                    if let Some(input) = state_machine.get_bool(name) {
+                        input.set(*val);
+                    }
+                }
+                InputValue::Number(val) => {
+                    if let Some(input) = state_machine.get_number(name) {
+                        input.set(*val);
+                    }
+                }
+                InputValue::Trigger => (),
+            }
+        }
+    }
+}
+
+/// The clock a scene is advanced by.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RiveClock {
+    /// [`Time<Virtual>`], which stops when the game is paused.
+    #[default]
+    Virtual,
+    /// [`Time<Real>`], e.g. for UI that keeps animating in pause menus.
+    Real,
+}
+
+/// Controls how a scene advances. Scenes without it play at normal speed on
+/// [`RiveClock::Virtual`].
+#[derive(Clone, Component, Copy, Debug, PartialEq)]
+pub struct RivePlayback {
+    /// Multiplier applied to the clock's delta. Negative and non-finite
+    /// values are treated as `0.0`.
+    pub speed: f32,
+    pub paused: bool,
+    pub clock: RiveClock,
+}
+
+impl Default for RivePlayback {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            paused: false,
+            clock: RiveClock::default(),
+        }
+    }
+}
 
-#[derive(Component, Debug)]
-pub(crate) struct MissingLinearAnimation;
+impl RivePlayback {
+    /// Returns how far the scene advances given the deltas of both clocks.
+    pub(crate) fn delta(&self, virtual_delta: Duration, real_delta: Duration) -> Duration {
+        if self.paused {
+            return Duration::ZERO;
+        }
 
-#[derive(Component, Debug)]
-pub(crate) struct MissingStateMachine;
+        let delta = match self.clock {
+            RiveClock::Virtual => virtual_delta,
+            RiveClock::Real => real_delta,
+        };
+
+        Duration::try_from_secs_f32(delta.as_secs_f32() * self.speed).unwrap_or_default()
+    }
+}
+
+/// How a scene's artboard is scaled to its target image.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RiveFit {
+    /// Stretches the artboard to cover the whole image.
+    Fill,
+    /// Scales the artboard uniformly to fit inside of the image.
+    #[default]
+    Contain,
+    /// Scales the artboard uniformly to cover the whole image.
+    Cover,
+    /// Scales the artboard uniformly to match the image's width.
+    FitWidth,
+    /// Scales the artboard uniformly to match the image's height.
+    FitHeight,
+    /// Draws the artboard at its original size.
+    None,
+    /// Like [`RiveFit::Contain`], but never scales the artboard up.
+    ScaleDown,
+}
+
+/// Where a scene's artboard is placed inside of its target image when their
+/// aspect ratios differ.
+///
+/// Both axes go from `-1.0` (left/top) to `1.0` (right/bottom).
+#[derive(Clone, Component, Copy, Debug, Deref, DerefMut, PartialEq)]
+pub struct RiveAlignment(pub Vec2);
+
+impl RiveAlignment {
+    pub const TOP_LEFT: Self = Self(Vec2::new(-1.0, -1.0));
+    pub const TOP_CENTER: Self = Self(Vec2::new(0.0, -1.0));
+    pub const TOP_RIGHT: Self = Self(Vec2::new(1.0, -1.0));
+    pub const CENTER_LEFT: Self = Self(Vec2::new(-1.0, 0.0));
+    pub const CENTER: Self = Self(Vec2::new(0.0, 0.0));
+    pub const CENTER_RIGHT: Self = Self(Vec2::new(1.0, 0.0));
+    pub const BOTTOM_LEFT: Self = Self(Vec2::new(-1.0, 1.0));
+    pub const BOTTOM_CENTER: Self = Self(Vec2::new(0.0, 1.0));
+    pub const BOTTOM_RIGHT: Self = Self(Vec2::new(1.0, 1.0));
+}
 
+impl Default for RiveAlignment {
+    fn default() -> Self {
+        Self::CENTER
+    }
+}
+
+/// Returns the transform from artboard space to an image of `target_size`.
+fn fit_artboard(
+    fit: RiveFit,
+    alignment: RiveAlignment,
+    artboard_size: Vec2,
+    target_size: Vec2,
+) -> Affine2 {
+    let ratio = target_size / artboard_size;
+    let scale = match fit {
+        RiveFit::Fill => ratio,
+        RiveFit::Contain => Vec2::splat(ratio.min_element()),
+        RiveFit::Cover => Vec2::splat(ratio.max_element()),
+        RiveFit::FitWidth => Vec2::splat(ratio.x),
+        RiveFit::FitHeight => Vec2::splat(ratio.y),
+        RiveFit::None => Vec2::ONE,
+        RiveFit::ScaleDown => Vec2::splat(ratio.min_element().min(1.0)),
+    };
+
+    let free_space = target_size - artboard_size * scale;
+    let translation = free_space * (*alignment + Vec2::ONE) / 2.0;
+
+    Affine2::from_scale_angle_translation(scale, 0.0, translation)
+}
+
+/// Returns the transform Rive applies to artboards inside of a viewport of
+/// `viewport_size`, which always centers and contains them.
+fn contain_in_viewport(artboard_size: Vec2, viewport_size: Vec2) -> Affine2 {
+    fit_artboard(
+        RiveFit::Contain,
+        RiveAlignment::CENTER,
+        artboard_size,
+        viewport_size,
+    )
+}
+
+/// The Rive viewport a scene is drawn in, along with how it maps to the
+/// scene's target image.
+///
+/// Rive always draws artboards centered and contained in the viewport, so the
+/// viewport is sized to the artboard and [`RiveFit`] and [`RiveAlignment`]
+/// are applied on top of that instead.
 #[derive(Clone, Component, Debug, Default, Deref, DerefMut)]
-pub struct Viewport(pub rive_rs::Viewport);
+pub struct Viewport {
+    #[deref]
+    pub inner: rive_rs::Viewport,
+    target_size: UVec2,
+    transform: Affine2,
+    is_dirty: bool,
+}
+
+impl Viewport {
+    pub fn target_size(&self) -> UVec2 {
+        self.target_size
+    }
+
+    pub fn set_target_size(&mut self, size: UVec2) {
+        if self.target_size != size {
+            self.target_size = size;
+            self.is_dirty = true;
+        }
+    }
+
+    /// Fits an artboard of `artboard_size` to the target image.
+    pub fn fit(&mut self, artboard_size: Vec2, fit: RiveFit, alignment: RiveAlignment) {
+        if artboard_size.cmple(Vec2::ZERO).any() {
+            return;
+        }
+
+        let size = artboard_size.ceil();
+        if self.inner.width() != size.x as u32 || self.inner.height() != size.y as u32 {
+            self.inner.resize(size.x as u32, size.y as u32);
+        }
+
+        // Undo the mapping that Rive applies inside of the viewport.
+        let viewport_to_artboard = contain_in_viewport(artboard_size, size).inverse();
+
+        let transform = fit_artboard(fit, alignment, artboard_size, self.target_size.as_vec2())
+            * viewport_to_artboard;
+
+        if self.transform != transform {
+            self.transform = transform;
+            self.is_dirty = true;
+        }
+    }
+
+    /// Maps a position in artboard units to the viewport, the same way Rive
+    /// draws the artboard inside of it.
+    pub fn artboard_to_viewport(&self, artboard_size: Vec2, pos: Vec2) -> Vec2 {
+        let size = Vec2::new(self.inner.width() as f32, self.inner.height() as f32);
+
+        contain_in_viewport(artboard_size, size).transform_point2(pos)
+    }
+
+    /// Maps a position in target image pixels to the viewport.
+    pub fn target_to_viewport(&self, pos: Vec2) -> Vec2 {
+        self.transform.inverse().transform_point2(pos)
+    }
+
+    /// The transform from viewport to target image space, for Vello.
+    pub fn affine(&self) -> Affine {
+        Affine::new(self.transform.to_cols_array().map(f64::from))
+    }
+
+    /// Returns whether the mapping to the target image changed since the last
+    /// call, in which case the scene must be drawn again.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.is_dirty)
+    }
+}
 
 #[derive(Clone, Component, Debug, Default, Deref)]
 pub struct MeshEntity {
     pub entity: Option<Entity>,
 }
 
+/// Which faces of a [`MeshEntity`]'s mesh receive pointer input. Insert it on
+/// the mesh entity itself.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MeshFaces {
+    /// Uses the cull mode of the mesh's `StandardMaterial`, or front faces
+    /// only for every other material type.
+    #[default]
+    Material,
+    Front,
+    Back,
+    DoubleSided,
+}
+
+impl MeshFaces {
+    pub(crate) fn cull_mode(self, material_cull_mode: Option<Option<Face>>) -> Option<Face> {
+        match self {
+            Self::Material => material_cull_mode.unwrap_or(Some(Face::Back)),
+            Self::Front => Some(Face::Back),
+            Self::Back => Some(Face::Front),
+            Self::DoubleSided => None,
+        }
+    }
+}
+
 #[derive(Clone, Component, Debug, Default, Deref)]
 pub struct SpriteEntity {
     pub entity: Option<Entity>,
@@ -56,6 +429,31 @@ pub struct SceneTarget {
     pub mesh: MeshEntity,
 }
 
+/// Requests a target image of a fixed size in pixels.
+///
+/// The target image is resized whenever this changes. Scenes spawned without
+/// a [`SceneTarget`] image get one sized to their artboard instead.
+#[derive(Clone, Component, Copy, Debug, Deref, DerefMut, Eq, PartialEq)]
+pub struct TargetResolution(pub UVec2);
+
+/// Creates an empty image that scenes can be rendered to.
+pub(crate) fn new_target_image(size: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x.max(1),
+            height: size.y.max(1),
+            ..default()
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::COPY_SRC;
+
+    image
+}
+
 #[derive(Component, Deref)]
 pub(crate) struct VelloFragment(pub Arc<SceneFragment>);
 
@@ -63,6 +461,7 @@ pub(crate) struct VelloFragment(pub Arc<SceneFragment>);
 pub(crate) struct VelloScene {
     pub fragment: Arc<vello::SceneFragment>,
     pub image_handle: Handle<Image>,
+    pub transform: Affine,
     pub width: u32,
     pub height: u32,
 }
@@ -74,7 +473,7 @@ impl ExtractComponent for VelloScene {
         &'static Viewport,
     );
 
-    type Filter = ();
+    type Filter = Changed<VelloFragment>;
 
     type Out = Self;
 
@@ -84,8 +483,236 @@ impl ExtractComponent for VelloScene {
         Some(Self {
             fragment: fragment.0.clone(),
             image_handle: image.clone(),
-            width: viewport.width(),
-            height: viewport.height(),
+            transform: viewport.affine(),
+            width: viewport.target_size().x,
+            height: viewport.target_size().y,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTBOARD_SIZE: Vec2 = Vec2::new(100.0, 50.0);
+
+    /// Returns where the artboard's origin lands and how much it is scaled.
+    fn fit(fit: RiveFit, alignment: RiveAlignment, target_size: Vec2) -> (Vec2, Vec2) {
+        let transform = fit_artboard(fit, alignment, ARTBOARD_SIZE, target_size);
+
+        (
+            transform.transform_point2(Vec2::ZERO),
+            transform.transform_vector2(Vec2::ONE),
+        )
+    }
+
+    #[test]
+    fn fits_to_tall_target() {
+        let target_size = Vec2::new(200.0, 200.0);
+        let cases = [
+            (RiveFit::Fill, Vec2::ZERO, Vec2::new(2.0, 4.0)),
+            (RiveFit::Contain, Vec2::new(0.0, 50.0), Vec2::splat(2.0)),
+            (RiveFit::Cover, Vec2::new(-100.0, 0.0), Vec2::splat(4.0)),
+            (RiveFit::FitWidth, Vec2::new(0.0, 50.0), Vec2::splat(2.0)),
+            (RiveFit::FitHeight, Vec2::new(-100.0, 0.0), Vec2::splat(4.0)),
+            (RiveFit::None, Vec2::new(50.0, 75.0), Vec2::ONE),
+            (RiveFit::ScaleDown, Vec2::new(50.0, 75.0), Vec2::ONE),
+        ];
+
+        for (rive_fit, origin, scale) in cases {
+            assert_eq!(
+                fit(rive_fit, RiveAlignment::CENTER, target_size),
+                (origin, scale),
+                "{rive_fit:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn fits_to_wide_target() {
+        let target_size = Vec2::new(400.0, 50.0);
+        let cases = [
+            (RiveFit::Fill, Vec2::ZERO, Vec2::new(4.0, 1.0)),
+            (RiveFit::Contain, Vec2::new(150.0, 0.0), Vec2::ONE),
+            (RiveFit::Cover, Vec2::new(0.0, -75.0), Vec2::splat(4.0)),
+            (RiveFit::FitWidth, Vec2::new(0.0, -75.0), Vec2::splat(4.0)),
+            (RiveFit::FitHeight, Vec2::new(150.0, 0.0), Vec2::ONE),
+            (RiveFit::None, Vec2::new(150.0, 0.0), Vec2::ONE),
+            (RiveFit::ScaleDown, Vec2::new(150.0, 0.0), Vec2::ONE),
+        ];
+
+        for (rive_fit, origin, scale) in cases {
+            assert_eq!(
+                fit(rive_fit, RiveAlignment::CENTER, target_size),
+                (origin, scale),
+                "{rive_fit:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn scale_down_only_shrinks() {
+        assert_eq!(
+            fit(RiveFit::ScaleDown, RiveAlignment::CENTER, Vec2::splat(50.0)),
+            (Vec2::new(0.0, 12.5), Vec2::splat(0.5))
+        );
+    }
+
+    #[test]
+    fn aligns_to_corners() {
+        let target_size = Vec2::new(200.0, 200.0);
+        let cases = [
+            (RiveAlignment::TOP_LEFT, Vec2::ZERO),
+            (RiveAlignment::TOP_CENTER, Vec2::new(50.0, 0.0)),
+            (RiveAlignment::TOP_RIGHT, Vec2::new(100.0, 0.0)),
+            (RiveAlignment::CENTER_LEFT, Vec2::new(0.0, 75.0)),
+            (RiveAlignment::CENTER, Vec2::new(50.0, 75.0)),
+            (RiveAlignment::CENTER_RIGHT, Vec2::new(100.0, 75.0)),
+            (RiveAlignment::BOTTOM_LEFT, Vec2::new(0.0, 150.0)),
+            (RiveAlignment::BOTTOM_CENTER, Vec2::new(50.0, 150.0)),
+            (RiveAlignment::BOTTOM_RIGHT, Vec2::new(100.0, 150.0)),
+        ];
+
+        for (alignment, origin) in cases {
+            assert_eq!(
+                fit(RiveFit::None, alignment, target_size),
+                (origin, Vec2::ONE),
+                "{alignment:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn viewport_round_trip() {
+        // Not a whole number of pixels, so that Rive's own mapping inside of
+        // the viewport is not the identity.
+        let artboard_size = Vec2::new(100.5, 50.0);
+        let points = [Vec2::ZERO, Vec2::new(100.5, 50.0), Vec2::new(25.0, 40.0)];
+
+        for rive_fit in [
+            RiveFit::Fill,
+            RiveFit::Contain,
+            RiveFit::Cover,
+            RiveFit::None,
+        ] {
+            for alignment in [RiveAlignment::TOP_LEFT, RiveAlignment::BOTTOM_RIGHT] {
+                let mut viewport = Viewport::default();
+                viewport.set_target_size(UVec2::new(200, 300));
+                viewport.fit(artboard_size, rive_fit, alignment);
+
+                let to_target =
+                    fit_artboard(rive_fit, alignment, artboard_size, Vec2::new(200.0, 300.0));
+
+                for point in points {
+                    let expected = viewport.artboard_to_viewport(artboard_size, point);
+                    let actual = viewport.target_to_viewport(to_target.transform_point2(point));
+
+                    assert!(
+                        actual.abs_diff_eq(expected, 1.0e-3),
+                        "{rive_fit:?} {alignment:?}: {actual} is not {expected}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn viewport_dirty_tracking() {
+        let mut viewport = Viewport::default();
+        viewport.set_target_size(UVec2::new(200, 200));
+        viewport.fit(ARTBOARD_SIZE, RiveFit::Contain, RiveAlignment::CENTER);
+        assert!(viewport.take_dirty());
+
+        viewport.fit(ARTBOARD_SIZE, RiveFit::Contain, RiveAlignment::CENTER);
+        assert!(!viewport.take_dirty());
+
+        viewport.fit(ARTBOARD_SIZE, RiveFit::Cover, RiveAlignment::CENTER);
+        assert!(viewport.take_dirty());
+
+        // Artboards without an area are ignored.
+        viewport.fit(Vec2::new(0.0, 50.0), RiveFit::Fill, RiveAlignment::CENTER);
+        assert!(!viewport.take_dirty());
+    }
+
+    #[test]
+    fn one_shot_finishes_once() {
+        assert_eq!(
+            progress_between((0.5, false), (0.8, false), Some(1.0)),
+            (false, false)
+        );
+        assert_eq!(
+            progress_between((0.8, false), (1.0, false), Some(1.0)),
+            (true, false)
+        );
+        // Stays at the end afterwards.
+        assert_eq!(
+            progress_between((1.0, false), (1.0, false), Some(1.0)),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn loop_wraps_around() {
+        assert_eq!(
+            progress_between((0.9, false), (0.1, false), Some(1.0)),
+            (false, true)
+        );
+        assert_eq!(
+            progress_between((0.1, false), (0.9, false), Some(1.0)),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn ping_pong_changes_direction() {
+        assert_eq!(
+            progress_between((0.9, false), (0.9, true), Some(1.0)),
+            (false, true)
+        );
+        assert_eq!(
+            progress_between((0.1, true), (0.1, false), Some(1.0)),
+            (false, true)
+        );
+        assert_eq!(
+            progress_between((0.9, true), (0.5, true), Some(1.0)),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn reversed_playback() {
+        // One-shot, played from the end towards the start.
+        assert_eq!(
+            progress_between((0.2, true), (0.0, true), Some(1.0)),
+            (true, false)
+        );
+        assert_eq!(
+            progress_between((0.0, true), (0.0, true), Some(1.0)),
+            (false, false)
+        );
+        // Looping backwards wraps to the end.
+        assert_eq!(
+            progress_between((0.1, true), (0.9, true), Some(1.0)),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn endless_animation_never_finishes() {
+        assert_eq!(
+            progress_between((5.0, false), (6.0, false), None),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn playback_speed() {
+        let delta = Duration::from_secs(1);
+        let delta_at = |speed| RivePlayback { speed, ..default() }.delta(delta, Duration::ZERO);
+
+        assert_eq!(delta_at(0.5), Duration::from_millis(500));
+        assert_eq!(delta_at(-1.0), Duration::ZERO);
+        assert_eq!(delta_at(f32::INFINITY), Duration::ZERO);
+        assert_eq!(delta_at(f32::NAN), Duration::ZERO);
+    }
+}
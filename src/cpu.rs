@@ -0,0 +1,211 @@
+use std::sync::Mutex;
+
+use bevy::{prelude::*, render::RenderApp, tasks::block_on};
+use vello::{kurbo::Affine, RenderParams, Renderer, RendererOptions, SceneBuilder};
+
+use crate::components::{VelloFragment, Viewport};
+
+/// Selects where the `VelloFragment`s produced by Rive scenes get rasterized.
+///
+/// Insert it before adding [`RivePlugin`](crate::RivePlugin) to force a
+/// backend. When absent, the GPU backend is used if the app has a render
+/// sub-app and the CPU backend otherwise.
+///
+/// The CPU backend still needs a wgpu adapter, since Vello only moves some of
+/// its stages to the CPU. A software adapter such as lavapipe or WARP is
+/// enough; without any, scenes are not drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Resource)]
+pub enum RiveRenderBackend {
+    /// Render in the render sub-app's `VelloNode`.
+    #[default]
+    Gpu,
+    /// Rasterize on the main world, preferably with a software adapter, and
+    /// write the pixels directly into the target `Image` assets.
+    Cpu,
+}
+
+struct CpuContextInner {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    renderer: Renderer,
+}
+
+#[derive(Resource)]
+pub(crate) struct CpuContext {
+    inner: Mutex<CpuContextInner>,
+}
+
+impl CpuContext {
+    fn request_device(force_fallback_adapter: bool) -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            force_fallback_adapter,
+            compatible_surface: None,
+        }))?;
+
+        block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None)).ok()
+    }
+
+    pub fn new() -> Option<Self> {
+        // Prefer the software adapter so that results do not depend on the
+        // host's GPU, but still work on machines that only expose hardware ones.
+        let (device, queue) = Self::request_device(true).or_else(|| Self::request_device(false))?;
+
+        let renderer = Renderer::new(
+            &device,
+            &RendererOptions {
+                surface_format: None,
+                timestamp_period: queue.get_timestamp_period(),
+                use_cpu: true,
+            },
+        )
+        .ok()?;
+
+        Some(Self {
+            inner: Mutex::new(CpuContextInner {
+                device,
+                queue,
+                renderer,
+            }),
+        })
+    }
+}
+
+fn padded_bytes_per_row(width: u32) -> u32 {
+    let unpadded = width * 4;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    unpadded.div_ceil(align) * align
+}
+
+impl CpuContextInner {
+    fn rasterize(
+        &mut self,
+        fragment: &vello::SceneFragment,
+        transform: Affine,
+        width: u32,
+        height: u32,
+    ) -> Vec<u8> {
+        let mut scene = vello::Scene::default();
+        let mut builder = SceneBuilder::for_scene(&mut scene);
+        builder.append(fragment, Some(transform));
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: None,
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        self.renderer
+            .render_to_texture(
+                &self.device,
+                &self.queue,
+                &scene,
+                &view,
+                &RenderParams {
+                    base_color: vello::peniko::Color::TRANSPARENT,
+                    width,
+                    height,
+                },
+            )
+            .expect("failed to render with Vello");
+
+        let bytes_per_row = padded_bytes_per_row(width);
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (bytes_per_row * height) as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| ());
+        self.device.poll(wgpu::Maintain::Wait);
+
+        let mut data = Vec::with_capacity((width * height * 4) as usize);
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(bytes_per_row as usize)
+        {
+            data.extend_from_slice(&row[..(width * 4) as usize]);
+        }
+
+        buffer.unmap();
+        texture.destroy();
+
+        data
+    }
+}
+
+pub(crate) fn rasterize_scenes(
+    context: Res<CpuContext>,
+    query: Query<(&VelloFragment, &Handle<Image>, &Viewport), Changed<VelloFragment>>,
+    mut image_assets: ResMut<Assets<Image>>,
+) {
+    let mut context = context.inner.lock().unwrap();
+
+    for (fragment, image_handle, viewport) in &query {
+        let Some(image) = image_assets.get_mut(image_handle) else {
+            continue;
+        };
+
+        let size = image.size();
+        if size.x == 0 || size.y == 0 {
+            continue;
+        }
+
+        image.data = context.rasterize(fragment, viewport.affine(), size.x, size.y);
+    }
+}
+
+/// Returns `true` if `app` should rasterize its scenes on the CPU.
+pub(crate) fn is_selected(app: &App) -> bool {
+    match app.world.get_resource::<RiveRenderBackend>() {
+        Some(backend) => *backend == RiveRenderBackend::Cpu,
+        None => app.get_sub_app(RenderApp).is_err(),
+    }
+}
+
+pub(crate) fn build(app: &mut App) {
+    // Target images are still managed without an adapter.
+    if !app.world.contains_resource::<Assets<Image>>() {
+        app.init_asset::<Image>();
+    }
+
+    let Some(context) = CpuContext::new() else {
+        error!("no wgpu adapter available for CPU rasterization; Rive scenes will not be drawn");
+        return;
+    };
+
+    app.insert_resource(context)
+        .add_systems(PostUpdate, rasterize_scenes);
+}
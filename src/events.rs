@@ -3,6 +3,8 @@ use std::{borrow::Cow, collections::BTreeMap, time::Duration};
 use bevy::prelude::*;
 use rive_rs::state_machine::Property;
 
+use crate::assets::Riv;
+
 #[derive(Clone, Debug)]
 pub enum InputValue {
     Bool(bool),
@@ -17,6 +19,34 @@ pub struct Input {
     pub value: InputValue,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PointerAction {
+    Down,
+    Up,
+    Move,
+    /// Moves the pointer out of the scene; the position is ignored.
+    Exit,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PointerSpace {
+    /// Pixels of the scene's target image, with the origin at the top left.
+    #[default]
+    Image,
+    /// Units of the scene's artboard, with the origin at the top left.
+    Artboard,
+}
+
+/// A synthetic pointer event passed to a scene alongside the mouse and touch
+/// input, e.g. from a virtual cursor or a UI test.
+#[derive(Clone, Debug, Event)]
+pub struct RivePointerEvent {
+    pub scene: Entity,
+    pub action: PointerAction,
+    pub position: Vec2,
+    pub space: PointerSpace,
+}
+
 #[derive(Clone, Debug, Event)]
 pub struct GenericEvent {
     pub state_machine: Entity,
@@ -24,3 +54,53 @@ pub struct GenericEvent {
     pub delay: Duration,
     pub properties: BTreeMap<String, Property>,
 }
+
+/// Sent when a one-shot linear animation reaches its end.
+#[derive(Clone, Debug, Event)]
+pub struct RiveAnimationFinished {
+    pub entity: Entity,
+    pub name: String,
+}
+
+/// Sent when a looping linear animation wraps around, or a ping-pong one
+/// changes direction.
+#[derive(Clone, Debug, Event)]
+pub struct RiveAnimationLooped {
+    pub entity: Entity,
+    pub name: String,
+}
+
+/// Sent when the active [`RiveLocale`](crate::RiveLocale) has no string for a
+/// text run, which then keeps its current text.
+#[derive(Clone, Debug, Event)]
+pub struct RiveMissingTranslation {
+    pub entity: Entity,
+    pub artboard: String,
+    pub run: String,
+    pub locale: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiveErrorKind {
+    /// The `.riv` itself failed to load; the [`AssetServer`] logs why.
+    LoadFailed,
+    MissingArtboard,
+    MissingLinearAnimation,
+    MissingStateMachine,
+}
+
+/// Sent once when a scene fails to instantiate, alongside
+/// [`RiveSceneStatus::Failed`](crate::RiveSceneStatus::Failed).
+#[derive(Clone, Debug, Event)]
+pub struct RiveError {
+    pub entity: Entity,
+    pub kind: RiveErrorKind,
+    /// The `.riv` of the scene.
+    pub riv: Handle<Riv>,
+    /// The handle that could not be found, or `None` for
+    /// [`RiveErrorKind::LoadFailed`].
+    pub handle: Option<rive_rs::Handle>,
+    /// Names of the artboards, linear animations or state machines that
+    /// `handle` could have referred to.
+    pub alternatives: Vec<String>,
+}
@@ -0,0 +1,275 @@
+use bevy::{
+    ecs::{system::EntityCommands, world::EntityWorldMut},
+    prelude::*,
+    utils::thiserror::{self, Error},
+};
+use rive_rs::state_machine::Input;
+
+use crate::{
+    catalog::{input_infos, InputInfo, InputKind},
+    components::RiveStateMachine,
+    events::InputValue,
+};
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum RiveInputError {
+    /// The state machine declares no input with this name.
+    #[error("input {0:?} cannot be found")]
+    Unknown(String),
+    /// The input exists but has a different type.
+    #[error("input {name:?} is a {actual}, not a {expected}")]
+    WrongType {
+        name: String,
+        expected: &'static str,
+        actual: &'static str,
+    },
+}
+
+fn type_name(kind: &InputKind) -> &'static str {
+    match kind {
+        InputKind::Bool(_) => "bool",
+        InputKind::Number(_) => "number",
+        InputKind::Trigger => "trigger",
+    }
+}
+
+/// Typed access to the inputs of an entity's state machine.
+///
+/// Inserted by the plugin when the state machine is instantiated. Writes are
+/// checked against the inputs declared in the file right away and applied to
+/// the state machine before it is next advanced, in the same frame for
+/// systems that run before [`RiveApplySet`](crate::RiveApplySet).
+#[derive(Component, Debug, Default)]
+pub struct RiveInputs {
+    declared: Vec<InputInfo>,
+    pending: Vec<(String, InputValue)>,
+}
+
+impl RiveInputs {
+    pub(crate) fn new(declared: Vec<InputInfo>) -> Self {
+        Self {
+            declared,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Inputs declared by the state machine, with their initial values.
+    pub fn declared(&self) -> &[InputInfo] {
+        &self.declared
+    }
+
+    fn check(&self, name: &str, value: &InputValue) -> Result<(), RiveInputError> {
+        let input = self
+            .declared
+            .iter()
+            .find(|input| input.name == name)
+            .ok_or_else(|| RiveInputError::Unknown(name.to_string()))?;
+
+        let expected = match value {
+            InputValue::Bool(_) => "bool",
+            InputValue::Number(_) => "number",
+            InputValue::Trigger => "trigger",
+        };
+        let actual = type_name(&input.kind);
+
+        if expected != actual {
+            return Err(RiveInputError::WrongType {
+                name: name.to_string(),
+                expected,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, name: &str, value: InputValue) -> Result<(), RiveInputError> {
+        self.check(name, &value)?;
+        self.pending.push((name.to_string(), value));
+
+        Ok(())
+    }
+
+    pub fn set_bool(&mut self, name: &str, value: bool) -> Result<(), RiveInputError> {
+        self.push(name, InputValue::Bool(value))
+    }
+
+    pub fn set_number(&mut self, name: &str, value: f32) -> Result<(), RiveInputError> {
+        self.push(name, InputValue::Number(value))
+    }
+
+    pub fn fire(&mut self, name: &str) -> Result<(), RiveInputError> {
+        self.push(name, InputValue::Trigger)
+    }
+}
+
+pub(crate) fn apply_inputs(
+    mut query: Query<(&mut RiveInputs, &RiveStateMachine), Changed<RiveInputs>>,
+) {
+    for (mut inputs, state_machine) in &mut query {
+        if inputs.pending.is_empty() {
+            continue;
+        }
+
+        for (name, value) in inputs.pending.drain(..) {
+            // Inputs were checked when queued, so lookups only fail if the
+            // state machine was swapped in between.
+            match value {
+                InputValue::Bool(val) => {
+                    if let Some(input) = state_machine.get_bool(&name) {
+                        input.set(val);
+                    }
+                }
+                InputValue::Number(val) => {
+                    if let Some(input) = state_machine.get_number(&name) {
+                        input.set(val);
+                    }
+                }
+                InputValue::Trigger => {
+                    if let Some(input) = state_machine.get_trigger(&name) {
+                        input.fire();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The current values of an entity's state machine inputs.
+///
+/// Updated by the plugin after the state machine advances, and only marked as
+/// changed when a value actually differs, so `Changed<RiveInputValues>` can be
+/// used to react to inputs set by the state machine itself, e.g. listeners.
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct RiveInputValues {
+    inputs: Vec<InputInfo>,
+}
+
+impl RiveInputValues {
+    pub fn iter(&self) -> impl Iterator<Item = &InputInfo> {
+        self.inputs.iter()
+    }
+
+    pub fn get(&self, name: &str) -> Option<InputKind> {
+        self.inputs
+            .iter()
+            .find(|input| input.name == name)
+            .map(|input| input.kind)
+    }
+
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name)? {
+            InputKind::Bool(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn get_number(&self, name: &str) -> Option<f32> {
+        match self.get(name)? {
+            InputKind::Number(val) => Some(val),
+            _ => None,
+        }
+    }
+}
+
+/// Updates `inputs` in place with the current values of `state_machine` and
+/// returns whether any of them differs.
+fn update_input_values(inputs: &mut Vec<InputInfo>, state_machine: &rive_rs::StateMachine) -> bool {
+    let mut changed = false;
+    let mut count = 0;
+
+    for input in state_machine.inputs() {
+        let Some(info) = inputs.get_mut(count) else {
+            *inputs = input_infos(state_machine);
+            return true;
+        };
+
+        let (same_input, kind) = match input {
+            Input::Bool(input) => (info.name == input.name(), InputKind::Bool(input.get())),
+            Input::Number(input) => (info.name == input.name(), InputKind::Number(input.get())),
+            Input::Trigger(input) => (info.name == input.name(), InputKind::Trigger),
+        };
+
+        // The inputs only change shape when the state machine is replaced.
+        if !same_input {
+            *inputs = input_infos(state_machine);
+            return true;
+        }
+
+        changed |= info.kind != kind;
+        info.kind = kind;
+        count += 1;
+    }
+
+    if count != inputs.len() {
+        inputs.truncate(count);
+        return true;
+    }
+
+    changed
+}
+
+pub(crate) fn mirror_input_values(
+    mut commands: Commands,
+    mut query: Query<(Entity, &RiveStateMachine, Option<&mut RiveInputValues>)>,
+) {
+    for (entity, state_machine, values) in &mut query {
+        let Some(mut values) = values else {
+            commands.entity(entity).insert(RiveInputValues {
+                inputs: input_infos(state_machine),
+            });
+            continue;
+        };
+
+        if update_input_values(&mut values.bypass_change_detection().inputs, state_machine) {
+            values.set_changed();
+        }
+    }
+}
+
+/// Sets state machine inputs through [`RiveInputs`] from [`Commands`].
+///
+/// Commands cannot see the state machine when they are queued, so unknown
+/// inputs, mistyped values and entities without a state machine are only
+/// logged as errors once the commands are applied. Query [`RiveInputs`]
+/// directly to get these errors back as a [`RiveInputError`] at call time.
+pub trait RiveInputsCommandsExt {
+    fn set_bool_input(&mut self, name: impl Into<String>, value: bool) -> &mut Self;
+    fn set_number_input(&mut self, name: impl Into<String>, value: f32) -> &mut Self;
+    fn fire_trigger_input(&mut self, name: impl Into<String>) -> &mut Self;
+}
+
+fn queue(mut entity: EntityWorldMut, name: &str, value: InputValue) {
+    let id = entity.id();
+    let Some(mut inputs) = entity.get_mut::<RiveInputs>() else {
+        error!("{id:?} has no instantiated state machine to set input {name:?} on");
+        return;
+    };
+
+    if let Err(error) = inputs.push(name, value) {
+        error!("cannot set input on {id:?}: {error}");
+    }
+}
+
+impl RiveInputsCommandsExt for EntityCommands<'_, '_, '_> {
+    fn set_bool_input(&mut self, name: impl Into<String>, value: bool) -> &mut Self {
+        let name = name.into();
+        self.add(move |entity: EntityWorldMut| {
+            queue(entity, &name, InputValue::Bool(value));
+        })
+    }
+
+    fn set_number_input(&mut self, name: impl Into<String>, value: f32) -> &mut Self {
+        let name = name.into();
+        self.add(move |entity: EntityWorldMut| {
+            queue(entity, &name, InputValue::Number(value));
+        })
+    }
+
+    fn fire_trigger_input(&mut self, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+        self.add(move |entity: EntityWorldMut| {
+            queue(entity, &name, InputValue::Trigger);
+        })
+    }
+}
@@ -1,20 +1,54 @@
 mod assets;
+mod bindings;
+mod catalog;
 mod components;
+#[cfg(feature = "cpu")]
+mod cpu;
 pub mod events;
+mod inputs;
+mod localization;
 mod node;
 mod plugin;
 mod pointer_events;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+mod text;
+mod ui;
 
 // Re-export rive-rs
 pub use rive_rs;
 
+#[cfg(feature = "cpu")]
+pub use crate::cpu::RiveRenderBackend;
+
 pub use crate::{
-    assets::Riv,
+    assets::{
+        Riv, RivArtboard, RivEmbeddedAssets, RivLinearAnimation, RivLoaderError, RivLoaderSettings,
+        RivStateMachine,
+    },
+    bindings::{InputBinding, RiveInputBindings},
+    catalog::{
+        ArtboardInfo, InputInfo, InputKind, LinearAnimationInfo, RivCatalog, StateMachineInfo,
+        TextRunInfo,
+    },
     components::{
-        LinearAnimation, MeshEntity, RiveLinearAnimation, RiveStateMachine, SceneTarget,
-        SpriteEntity, StateMachine,
+        LinearAnimation, MeshEntity, MeshFaces, RiveAlignment, RiveClock, RiveFit,
+        RiveLinearAnimation, RivePlayback, RiveSceneStatus, RiveStateMachine, SceneTarget,
+        SpriteEntity, StateMachine, TargetResolution,
+    },
+    events::{
+        GenericEvent, RiveAnimationFinished, RiveAnimationLooped, RiveError, RiveErrorKind,
+        RiveMissingTranslation, RivePointerEvent,
+    },
+    inputs::{RiveInputError, RiveInputValues, RiveInputs, RiveInputsCommandsExt},
+    localization::{
+        RiveLocale, RiveLocaleTable, RiveLocaleTableLoader, RiveLocaleTableLoaderError,
+    },
+    plugin::{RiveApplySet, RivePlugin},
+    pointer_events::{
+        PointerCapture, PointerCaptures, PointerEventsSet, PointerInput, TouchPolicy,
     },
-    events::GenericEvent,
-    plugin::RivePlugin,
     rive_rs::Handle,
+    text::{RiveText, RiveTextBindings, RiveTextError, TextBinding},
+    ui::{RiveUiBundle, RiveUiNode},
 };
@@ -0,0 +1,152 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    reflect::TypePath,
+    utils::{
+        thiserror::{self, Error},
+        BoxedFuture, HashMap,
+    },
+};
+use serde::Deserialize;
+
+use crate::{
+    components::{RiveLinearAnimation, RiveStateMachine},
+    events::RiveMissingTranslation,
+    text::RiveText,
+};
+
+/// Translations of the text runs of one or more `.riv` files, loaded from
+/// `.rivlocale.ron` files:
+///
+/// ```ron
+/// (
+///     runs: {
+///         "Menu": { "Title": "menu.title", "Play": "menu.play" },
+///     },
+///     strings: {
+///         "en": { "menu.title": "Main menu", "menu.play": "Play" },
+///         "fr": { "menu.title": "Menu principal", "menu.play": "Jouer" },
+///     },
+/// )
+/// ```
+#[derive(Asset, Clone, Debug, Default, Deserialize, TypePath)]
+pub struct RiveLocaleTable {
+    /// Key of each text run, by artboard name then run path.
+    #[serde(default)]
+    pub runs: HashMap<String, HashMap<String, String>>,
+    /// Strings of each key, by locale then key.
+    #[serde(default)]
+    pub strings: HashMap<String, HashMap<String, String>>,
+}
+
+impl RiveLocaleTable {
+    /// Returns the text of `run` in `artboard` for `locale`.
+    pub fn translate(&self, locale: &str, artboard: &str, run: &str) -> Option<&str> {
+        let key = self.runs.get(artboard)?.get(run)?;
+
+        self.strings.get(locale)?.get(key).map(String::as_str)
+    }
+}
+
+/// The active locale. Text runs of every scene are set from `table` whenever
+/// this resource or the table changes, and when scenes are instantiated.
+#[derive(Clone, Debug, Resource)]
+pub struct RiveLocale {
+    pub locale: String,
+    pub table: Handle<RiveLocaleTable>,
+}
+
+#[derive(Debug, Error)]
+pub enum RiveLocaleTableLoaderError {
+    /// An [IO](std::io) Error.
+    #[error("Could not load locale table: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error.
+    #[error("Could not parse locale table: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+pub struct RiveLocaleTableLoader;
+
+impl AssetLoader for RiveLocaleTableLoader {
+    type Asset = RiveLocaleTable;
+    type Settings = ();
+    type Error = RiveLocaleTableLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+
+            Ok(ron::de::from_bytes(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rivlocale.ron"]
+    }
+}
+
+pub(crate) fn localize_text(
+    locale: Option<Res<RiveLocale>>,
+    tables: Res<Assets<RiveLocaleTable>>,
+    mut table_events: EventReader<AssetEvent<RiveLocaleTable>>,
+    mut query: Query<(
+        Entity,
+        &mut RiveText,
+        Option<&RiveLinearAnimation>,
+        Option<&RiveStateMachine>,
+    )>,
+    mut missing: EventWriter<RiveMissingTranslation>,
+) {
+    let Some(locale) = locale else {
+        return;
+    };
+
+    let table_changed = table_events.read().fold(false, |changed, event| {
+        changed
+            || event.is_loaded_with_dependencies(&locale.table)
+            || event.is_modified(&locale.table)
+    });
+    let Some(table) = tables.get(&locale.table) else {
+        return;
+    };
+    let relocalize = locale.is_changed() || table_changed;
+
+    for (entity, mut text, linear_animation, state_machine) in &mut query {
+        if !relocalize && !text.is_added() {
+            continue;
+        }
+
+        let artboard = match (linear_animation, state_machine) {
+            (Some(linear_animation), None) => linear_animation.artboard().name().to_string(),
+            (None, Some(state_machine)) => state_machine.artboard().name().to_string(),
+            _ => continue,
+        };
+
+        let runs: Vec<String> = text.runs().map(String::from).collect();
+        for run in runs {
+            let Some(translation) = table.translate(&locale.locale, &artboard, &run) else {
+                missing.send(RiveMissingTranslation {
+                    entity,
+                    artboard: artboard.clone(),
+                    run,
+                    locale: locale.locale.clone(),
+                });
+
+                continue;
+            };
+
+            if text.get(&run) != Some(translation) {
+                // Runs come from `text` itself.
+                let _ = text.set(&run, translation);
+            }
+        }
+    }
+}
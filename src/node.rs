@@ -16,7 +16,11 @@ use bevy::{
     },
 };
 use etagere::{euclid::Size2D, AllocId, Allocation, AtlasAllocator, Rectangle};
-use vello::{kurbo::Affine, RenderParams, Renderer, RendererOptions, SceneBuilder};
+use vello::{
+    kurbo::{Affine, Rect},
+    peniko::Mix,
+    RenderParams, Renderer, RendererOptions, SceneBuilder,
+};
 
 use crate::components::VelloScene;
 
@@ -199,17 +203,34 @@ impl Node for VelloNode {
         let mut builder = SceneBuilder::for_scene(&mut scene);
         let mut max_size = (0, 0);
 
-        for (entity, VelloScene { fragment, .. }) in self
+        for (
+            entity,
+            VelloScene {
+                fragment,
+                transform,
+                width,
+                height,
+                ..
+            },
+        ) in self
             .scene_entities
             .iter()
             .copied()
             .filter_map(|e| world.get::<VelloScene>(e).map(|s| (e, s)))
         {
             let rect = atlas.get(entity);
-            builder.append(
-                fragment,
-                Some(Affine::translate((rect.min.x as f64, rect.min.y as f64))),
+            let origin = Affine::translate((rect.min.x as f64, rect.min.y as f64));
+
+            // Fits such as `RiveFit::Cover` draw past the target, which would
+            // spill into the neighbouring scenes of the atlas.
+            builder.push_layer(
+                Mix::Clip,
+                1.0,
+                origin,
+                &Rect::new(0.0, 0.0, *width as f64, *height as f64),
             );
+            builder.append(fragment, Some(origin * *transform));
+            builder.pop_layer();
 
             max_size.0 = max_size.0.max(rect.max.x as u32);
             max_size.1 = max_size.1.max(rect.max.y as u32);
@@ -242,8 +263,12 @@ impl Node for VelloNode {
             .copied()
             .filter_map(|e| world.get::<VelloScene>(e).map(|s| (e, s)))
         {
-            let gpu_image = gpu_images.get(image_handle).unwrap();
+            // The image may not have been uploaded to the GPU yet.
+            let Some(gpu_image) = gpu_images.get(image_handle) else {
+                continue;
+            };
             let rect = atlas.get(entity);
+            let texture_size = gpu_image.texture.size();
 
             render_context.command_encoder().copy_texture_to_texture(
                 ImageCopyTexture {
@@ -262,7 +287,11 @@ impl Node for VelloNode {
                     origin: Origin3d::ZERO,
                     aspect: TextureAspect::All,
                 },
-                gpu_image.texture.size(),
+                Extent3d {
+                    width: texture_size.width.min(rect.width() as u32),
+                    height: texture_size.height.min(rect.height() as u32),
+                    ..texture_size
+                },
             );
         }
 
@@ -1,27 +1,43 @@
 use std::sync::Arc;
 
 use bevy::{
+    asset::{AssetPath, LoadState},
     core_pipeline::{core_2d, core_3d},
     ecs::query::BatchingStrategy,
     prelude::*,
     render::{
-        extract_component::ExtractComponentPlugin, render_graph::RenderGraphApp, Render, RenderApp,
-        RenderSet,
+        extract_component::ExtractComponentPlugin, render_graph::RenderGraphApp,
+        render_resource::Extent3d, Render, RenderApp, RenderSet,
     },
-    utils::HashMap,
+    ui::UiSystem,
+    utils::{HashMap, HashSet},
 };
 use rive_rs::Instantiate;
 
 use crate::{
     assets::{self, Riv, RivLoader},
+    bindings,
+    catalog::input_infos,
     components::{
-        LinearAnimation, MissingArtboard, MissingLinearAnimation, MissingStateMachine,
-        RiveLinearAnimation, RiveStateMachine, StateMachine, VelloFragment, VelloScene, Viewport,
+        new_target_image, LinearAnimation, ReloadState, RiveAlignment, RiveFit,
+        RiveLinearAnimation, RivePlayback, RiveSceneStatus, RiveStateMachine, SpriteEntity,
+        StateMachine, TargetResolution, VelloFragment, VelloScene, Viewport,
     },
-    events::{GenericEvent, Input, InputValue},
-    node, pointer_events,
+    events::{
+        GenericEvent, Input, InputValue, RiveAnimationFinished, RiveAnimationLooped, RiveError,
+        RiveErrorKind, RiveMissingTranslation, RivePointerEvent,
+    },
+    inputs::{self, RiveInputs},
+    localization::{self, RiveLocaleTable, RiveLocaleTableLoader},
+    node,
+    pointer_events::{self, PointerCaptures, PointerEventsSet, TouchPolicy},
+    text::{self, text_runs, RiveText},
+    ui::{resize_ui_images, RiveUiNode},
 };
 
+#[cfg(feature = "cpu")]
+use crate::cpu;
+
 macro_rules! get_scene_or {
     ( $keyword:tt, $linear_animation:expr, $state_machine:expr ) => {{
         let linear_animation = $linear_animation
@@ -39,6 +55,13 @@ macro_rules! get_scene_or {
 
 pub(crate) use get_scene_or;
 
+/// The systems that apply [`RiveInputs`](crate::RiveInputs),
+/// [`RiveText`](crate::RiveText) and their bindings to scenes, right before
+/// the scenes advance. Systems that write to them run `.before(RiveApplySet)`
+/// for their changes to show up in the same frame.
+#[derive(SystemSet, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RiveApplySet;
+
 macro_rules! get_or_continue_with_error {
     ( $val:expr, $( $tail:tt )* ) => {
         match $val {
@@ -51,12 +74,108 @@ macro_rules! get_or_continue_with_error {
     };
 }
 
+fn update_target_images(
+    mut commands: Commands,
+    mut queries: ParamSet<(
+        Query<
+            (
+                Entity,
+                Option<&mut Handle<Image>>,
+                Option<&TargetResolution>,
+                Option<&SpriteEntity>,
+                Option<&RiveLinearAnimation>,
+                Option<&RiveStateMachine>,
+            ),
+            (
+                Or<(With<LinearAnimation>, With<StateMachine>)>,
+                Without<RiveUiNode>,
+            ),
+        >,
+        Query<&mut Handle<Image>, With<Sprite>>,
+    )>,
+    mut image_assets: ResMut<Assets<Image>>,
+) {
+    let mut sprite_images = Vec::new();
+
+    for (entity, image_handle, resolution, sprite_entity, linear_animation, state_machine) in
+        &mut queries.p0()
+    {
+        // The default handle points to Bevy's shared placeholder image.
+        let has_image = image_handle
+            .as_deref()
+            .is_some_and(|handle| *handle != Handle::default());
+        let current_size = image_handle
+            .as_deref()
+            .filter(|_| has_image)
+            .and_then(|handle| image_assets.get(handle))
+            .map(Image::size);
+
+        // User-provided images may still be loading.
+        if has_image && current_size.is_none() {
+            continue;
+        }
+
+        let size = match (resolution, current_size) {
+            (Some(resolution), _) => **resolution,
+            (None, None) => {
+                let scene: &dyn rive_rs::Scene = match (linear_animation, state_machine) {
+                    (Some(linear_animation), None) => &**linear_animation,
+                    (None, Some(state_machine)) => &**state_machine,
+                    // Wait for the artboard to be instantiated.
+                    _ => continue,
+                };
+
+                Vec2::new(scene.width(), scene.height()).ceil().as_uvec2()
+            }
+            (None, Some(_)) => continue,
+        };
+
+        match (image_handle, current_size) {
+            (Some(image_handle), Some(current_size)) => {
+                if current_size != size {
+                    if let Some(image) = image_assets.get_mut(&*image_handle) {
+                        image.resize(Extent3d {
+                            width: size.x.max(1),
+                            height: size.y.max(1),
+                            ..default()
+                        });
+                    }
+                }
+            }
+            (image_handle, _) => {
+                let new_handle = image_assets.add(new_target_image(size));
+
+                if let Some(sprite) = sprite_entity.and_then(|sprite_entity| sprite_entity.entity) {
+                    sprite_images.push((sprite, new_handle.clone()));
+                }
+
+                match image_handle {
+                    Some(mut image_handle) => *image_handle = new_handle,
+                    None => {
+                        commands.entity(entity).insert(new_handle);
+                    }
+                }
+            }
+        }
+    }
+
+    // Sprites displaying the placeholder now display the scene.
+    let mut sprites = queries.p1();
+    for (sprite, new_handle) in sprite_images {
+        if let Ok(mut sprite_image) = sprites.get_mut(sprite) {
+            if *sprite_image == Handle::default() {
+                *sprite_image = new_handle;
+            }
+        }
+    }
+}
+
 fn insert_deafult_viewports(
     mut commands: Commands,
     query: Query<
         (Entity, &Handle<Image>),
         (
-            Or<(Added<LinearAnimation>, Added<StateMachine>)>,
+            Or<(With<LinearAnimation>, With<StateMachine>)>,
             Without<Viewport>,
         ),
     >,
@@ -66,8 +185,7 @@ fn insert_deafult_viewports(
         let mut viewport = Viewport::default();
 
         if let Some(image) = image_assets.get(image_handle) {
-            let size = image.size();
-            viewport.resize(size.x, size.y);
+            viewport.set_target_size(image.size());
         }
 
         commands.entity(entity).insert(viewport);
@@ -80,14 +198,174 @@ fn resize_viewports(
 ) {
     for (mut viewport, image_handle) in &mut query {
         if let Some(image) = image_assets.get(image_handle) {
-            let size = image.size();
-            viewport.resize(size.x, size.y);
+            viewport.set_target_size(image.size());
+        }
+    }
+}
+
+/// Loads the `Riv` that a labeled sub-asset at `path` was loaded from.
+fn riv_of(asset_server: &AssetServer, path: Option<&AssetPath<'static>>) -> Option<Handle<Riv>> {
+    Some(asset_server.load(path?.without_label().into_owned()))
+}
+
+/// Turns `Handle<RivLinearAnimation>` and `Handle<RivStateMachine>` components
+/// into the `LinearAnimation` and `StateMachine` they refer to.
+fn insert_scenes_from_assets(
+    mut commands: Commands,
+    mut linear_animations: Query<
+        (
+            Entity,
+            Ref<Handle<assets::RivLinearAnimation>>,
+            Option<&mut LinearAnimation>,
+        ),
+        Without<StateMachine>,
+    >,
+    mut state_machines: Query<
+        (
+            Entity,
+            Ref<Handle<assets::RivStateMachine>>,
+            Option<&mut StateMachine>,
+        ),
+        Without<LinearAnimation>,
+    >,
+    asset_server: Res<AssetServer>,
+    linear_animation_assets: Res<Assets<assets::RivLinearAnimation>>,
+    state_machine_assets: Res<Assets<assets::RivStateMachine>>,
+) {
+    for (entity, handle, current) in &mut linear_animations {
+        if current.is_some() && !handle.is_changed() {
+            continue;
+        }
+
+        let Some(asset) = linear_animation_assets.get(&*handle) else {
+            continue;
+        };
+        let Some(riv) = riv_of(&asset_server, handle.path()) else {
+            continue;
+        };
+
+        let linear_animation = LinearAnimation {
+            riv,
+            artboard_handle: rive_rs::Handle::Name(asset.artboard.clone().into()),
+            handle: rive_rs::Handle::Name(asset.name.clone().into()),
+            sprite_entity: current.as_ref().and_then(|current| current.sprite_entity),
+        };
+
+        match current {
+            Some(mut current) => {
+                *current = linear_animation;
+                commands.entity(entity).remove::<RiveLinearAnimation>();
+            }
+            None => {
+                commands.entity(entity).insert(linear_animation);
+            }
+        }
+    }
+
+    for (entity, handle, current) in &mut state_machines {
+        if current.is_some() && !handle.is_changed() {
+            continue;
+        }
+
+        let Some(asset) = state_machine_assets.get(&*handle) else {
+            continue;
+        };
+        let Some(riv) = riv_of(&asset_server, handle.path()) else {
+            continue;
+        };
+
+        let state_machine = StateMachine {
+            riv,
+            artboard_handle: rive_rs::Handle::Name(asset.artboard.clone().into()),
+            handle: rive_rs::Handle::Name(asset.name.clone().into()),
+            sprite_entity: current.as_ref().and_then(|current| current.sprite_entity),
+        };
+
+        match current {
+            Some(mut current) => {
+                *current = state_machine;
+                commands.entity(entity).remove::<RiveStateMachine>();
+            }
+            None => {
+                commands.entity(entity).insert(state_machine);
+            }
         }
     }
 }
 
 #[derive(Debug, Default, Deref, DerefMut, Resource)]
-struct RivEntities(HashMap<AssetId<assets::Riv>, Entity>);
+struct RivEntities(HashMap<AssetId<assets::Riv>, HashSet<Entity>>);
+
+fn artboard_names(riv: &Riv) -> Vec<String> {
+    riv.catalog().artboard_names().map(String::from).collect()
+}
+
+fn linear_animation_names(riv: &Riv, artboard: &rive_rs::Artboard) -> Vec<String> {
+    riv.catalog()
+        .artboard(&artboard.name())
+        .map(|info| {
+            info.linear_animations
+                .iter()
+                .map(|linear_animation| linear_animation.name.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn state_machine_names(riv: &Riv, artboard: &rive_rs::Artboard) -> Vec<String> {
+    riv.catalog()
+        .artboard(&artboard.name())
+        .map(|info| {
+            info.state_machines
+                .iter()
+                .map(|state_machine| state_machine.name.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Marks `entity` as failed, reporting the error only the first time.
+#[allow(clippy::too_many_arguments)]
+fn fail_instantiation(
+    commands: &mut Commands,
+    errors: &mut EventWriter<RiveError>,
+    entity: Entity,
+    status: Option<&RiveSceneStatus>,
+    kind: RiveErrorKind,
+    riv: &Handle<assets::Riv>,
+    handle: Option<&rive_rs::Handle>,
+    alternatives: impl FnOnce() -> Vec<String>,
+) {
+    if status == Some(&RiveSceneStatus::Failed(kind)) {
+        return;
+    }
+
+    let alternatives = alternatives();
+    let what = match kind {
+        RiveErrorKind::LoadFailed => None,
+        RiveErrorKind::MissingArtboard => Some("artboard"),
+        RiveErrorKind::MissingLinearAnimation => Some("linear animation"),
+        RiveErrorKind::MissingStateMachine => Some("state machine"),
+    };
+
+    match what.zip(handle) {
+        Some((what, handle)) => {
+            error!("{what} {handle:?} cannot be found in {entity:?}; available: {alternatives:?}")
+        }
+        None => error!("{:?} of {entity:?} failed to load", riv.path()),
+    }
+
+    commands
+        .entity(entity)
+        .insert(RiveSceneStatus::Failed(kind));
+    errors.send(RiveError {
+        entity,
+        kind,
+        riv: riv.clone(),
+        handle: handle.cloned(),
+        alternatives,
+    });
+}
 
 fn instantiate_linear_animations(
     mut commands: Commands,
@@ -95,64 +373,84 @@ fn instantiate_linear_animations(
         (
             Entity,
             &LinearAnimation,
-            Option<&MissingArtboard>,
-            Option<&MissingLinearAnimation>,
+            Option<&RiveSceneStatus>,
+            Option<&ReloadState>,
         ),
         Without<RiveLinearAnimation>,
     >,
     riv_assets: Res<Assets<assets::Riv>>,
+    asset_server: Res<AssetServer>,
     mut riv_entities: ResMut<RivEntities>,
+    mut errors: EventWriter<RiveError>,
 ) {
-    for (entity, linear_animation, missing_artboard, missing_linear_animation) in &query {
-        if let Some(riv) = riv_assets.get(&linear_animation.riv) {
-            let handle = linear_animation.riv.clone();
-            let artboard =
-                match rive_rs::Artboard::instantiate(riv, linear_animation.artboard_handle.clone())
-                {
-                    Some(artboard) => artboard,
-                    None => {
-                        if missing_artboard.is_none() {
-                            commands.entity(entity).insert(MissingArtboard);
-
-                            error!(
-                                "artboard {:?} cannot be found in {:?}",
-                                linear_animation.artboard_handle, riv,
-                            );
-                        }
+    for (entity, linear_animation, status, reload_state) in &query {
+        let Some(riv) = riv_assets.get(&linear_animation.riv) else {
+            if asset_server.get_load_state(&linear_animation.riv) == Some(LoadState::Failed) {
+                fail_instantiation(
+                    &mut commands,
+                    &mut errors,
+                    entity,
+                    status,
+                    RiveErrorKind::LoadFailed,
+                    &linear_animation.riv,
+                    None,
+                    Vec::new,
+                );
+            } else if status.is_none() {
+                commands.entity(entity).insert(RiveSceneStatus::Loading);
+            }
 
-                        continue;
-                    }
-                };
+            continue;
+        };
 
-            commands.entity(entity).remove::<MissingArtboard>();
-
-            let linear_animation = match rive_rs::LinearAnimation::instantiate(
-                &artboard,
-                linear_animation.handle.clone(),
-            ) {
-                Some(linear_animation) => linear_animation,
-                None => {
-                    if missing_linear_animation.is_none() {
-                        commands.entity(entity).insert(MissingLinearAnimation);
-
-                        error!(
-                            "linear animation {:?} cannot be found in {:?}",
-                            linear_animation.handle, riv,
-                        );
-                    }
+        let handle = linear_animation.riv.clone();
+        let Some(artboard) = rive_rs::Artboard::instantiate(
+            riv,
+            riv.artboard_handle(&linear_animation.artboard_handle),
+        ) else {
+            fail_instantiation(
+                &mut commands,
+                &mut errors,
+                entity,
+                status,
+                RiveErrorKind::MissingArtboard,
+                &linear_animation.riv,
+                Some(&linear_animation.artboard_handle),
+                || artboard_names(riv),
+            );
 
-                    continue;
-                }
-            };
+            continue;
+        };
 
-            commands.entity(entity).remove::<MissingLinearAnimation>();
+        let Some(mut instance) =
+            rive_rs::LinearAnimation::instantiate(&artboard, linear_animation.handle.clone())
+        else {
+            fail_instantiation(
+                &mut commands,
+                &mut errors,
+                entity,
+                status,
+                RiveErrorKind::MissingLinearAnimation,
+                &linear_animation.riv,
+                Some(&linear_animation.handle),
+                || linear_animation_names(riv, &artboard),
+            );
 
-            commands
-                .entity(entity)
-                .insert(RiveLinearAnimation(linear_animation));
+            continue;
+        };
 
-            riv_entities.insert(handle.id(), entity);
+        if let Some(reload_state) = reload_state {
+            reload_state.restore_linear_animation(&mut instance);
+            commands.entity(entity).remove::<ReloadState>();
         }
+
+        commands.entity(entity).insert((
+            RiveText::new(text_runs(&mut instance.artboard())),
+            RiveLinearAnimation(instance),
+            RiveSceneStatus::Ready,
+        ));
+
+        riv_entities.entry(handle.id()).or_default().insert(entity);
     }
 }
 
@@ -162,61 +460,85 @@ fn instantiate_state_machines(
         (
             Entity,
             &StateMachine,
-            Option<&MissingArtboard>,
-            Option<&MissingStateMachine>,
+            Option<&RiveSceneStatus>,
+            Option<&ReloadState>,
         ),
         Without<RiveStateMachine>,
     >,
     riv_assets: Res<Assets<assets::Riv>>,
+    asset_server: Res<AssetServer>,
     mut riv_entities: ResMut<RivEntities>,
+    mut errors: EventWriter<RiveError>,
 ) {
-    for (entity, state_machine, missing_artboard, missing_state_machine) in &query {
-        if let Some(riv) = riv_assets.get(&state_machine.riv) {
-            let handle = state_machine.riv.clone();
-            let artboard =
-                match rive_rs::Artboard::instantiate(riv, state_machine.artboard_handle.clone()) {
-                    Some(artboard) => artboard,
-                    None => {
-                        if missing_artboard.is_none() {
-                            commands.entity(entity).insert(MissingArtboard);
-
-                            error!(
-                                "artboard {:?} cannot be found in {:?}",
-                                state_machine.artboard_handle, riv,
-                            );
-                        }
+    for (entity, state_machine, status, reload_state) in &query {
+        let Some(riv) = riv_assets.get(&state_machine.riv) else {
+            if asset_server.get_load_state(&state_machine.riv) == Some(LoadState::Failed) {
+                fail_instantiation(
+                    &mut commands,
+                    &mut errors,
+                    entity,
+                    status,
+                    RiveErrorKind::LoadFailed,
+                    &state_machine.riv,
+                    None,
+                    Vec::new,
+                );
+            } else if status.is_none() {
+                commands.entity(entity).insert(RiveSceneStatus::Loading);
+            }
 
-                        continue;
-                    }
-                };
+            continue;
+        };
 
-            commands.entity(entity).remove::<MissingArtboard>();
+        let handle = state_machine.riv.clone();
+        let Some(artboard) = rive_rs::Artboard::instantiate(
+            riv,
+            riv.artboard_handle(&state_machine.artboard_handle),
+        ) else {
+            fail_instantiation(
+                &mut commands,
+                &mut errors,
+                entity,
+                status,
+                RiveErrorKind::MissingArtboard,
+                &state_machine.riv,
+                Some(&state_machine.artboard_handle),
+                || artboard_names(riv),
+            );
 
-            let state_machine =
-                match rive_rs::StateMachine::instantiate(&artboard, state_machine.handle.clone()) {
-                    Some(state_machine) => state_machine,
-                    None => {
-                        if missing_state_machine.is_none() {
-                            commands.entity(entity).insert(MissingStateMachine);
+            continue;
+        };
 
-                            error!(
-                                "linear animation {:?} cannot be found in {:?}",
-                                state_machine.handle, riv,
-                            );
-                        }
+        let Some(instance) =
+            rive_rs::StateMachine::instantiate(&artboard, state_machine.handle.clone())
+        else {
+            fail_instantiation(
+                &mut commands,
+                &mut errors,
+                entity,
+                status,
+                RiveErrorKind::MissingStateMachine,
+                &state_machine.riv,
+                Some(&state_machine.handle),
+                || state_machine_names(riv, &artboard),
+            );
 
-                        continue;
-                    }
-                };
+            continue;
+        };
 
-            commands.entity(entity).remove::<MissingStateMachine>();
+        if let Some(reload_state) = reload_state {
+            reload_state.restore_state_machine(&instance);
+            commands.entity(entity).remove::<ReloadState>();
+        }
 
-            commands
-                .entity(entity)
-                .insert(RiveStateMachine(state_machine));
+        commands.entity(entity).insert((
+            RiveInputs::new(input_infos(&instance)),
+            RiveText::new(text_runs(&mut instance.artboard())),
+            RiveStateMachine(instance),
+            RiveSceneStatus::Ready,
+        ));
 
-            riv_entities.insert(handle.id(), entity);
-        }
+        riv_entities.entry(handle.id()).or_default().insert(entity);
     }
 }
 
@@ -224,14 +546,32 @@ fn reinstantiate_linear_animations(
     mut commands: Commands,
     mut asset_events: EventReader<AssetEvent<assets::Riv>>,
     mut riv_entities: ResMut<RivEntities>,
+    scenes: Query<(Option<&RiveLinearAnimation>, Option<&RiveStateMachine>)>,
 ) {
     for event in asset_events.read() {
         match event {
             AssetEvent::Modified { id } => {
-                commands
-                    .entity(*riv_entities.get(id).unwrap())
-                    .remove::<RiveLinearAnimation>()
-                    .remove::<RiveStateMachine>();
+                let Some(entities) = riv_entities.get_mut(id) else {
+                    continue;
+                };
+
+                // Forget about entities that were despawned in the meantime.
+                entities.retain(|entity| scenes.contains(*entity));
+
+                for &entity in entities.iter() {
+                    let Ok((linear_animation, state_machine)) = scenes.get(entity) else {
+                        continue;
+                    };
+
+                    commands
+                        .entity(entity)
+                        .insert((
+                            ReloadState::capture(linear_animation, state_machine),
+                            RiveSceneStatus::Loading,
+                        ))
+                        .remove::<RiveLinearAnimation>()
+                        .remove::<RiveStateMachine>();
+                }
             }
             AssetEvent::Removed { id } => {
                 riv_entities.remove(id);
@@ -275,36 +615,94 @@ fn pass_state_machine_input_events(
 }
 
 fn render_rive_scenes(
-    time: Res<Time>,
+    time: Res<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
     par_commands: ParallelCommands,
     mut query: Query<(
         Entity,
         Option<&mut RiveLinearAnimation>,
         Option<&mut RiveStateMachine>,
         &mut Viewport,
+        Option<&RiveFit>,
+        Option<&RiveAlignment>,
+        Option<&VelloFragment>,
+        Option<&RivePlayback>,
     )>,
 ) {
     const MAX_SCENES_PER_CORE: usize = 8;
 
-    let elapsed = time.delta();
+    let virtual_delta = time.delta();
+    let real_delta = real_time.delta();
 
     query
         .par_iter_mut()
         .batching_strategy(BatchingStrategy::new().max_batch_size(MAX_SCENES_PER_CORE))
-        .for_each(|(entity, linear_animation, state_machine, mut viewport)| {
-            let mut renderer = rive_rs::Renderer::default();
-            let mut scene = get_scene_or!(return, linear_animation, state_machine);
+        .for_each(
+            |(
+                entity,
+                mut linear_animation,
+                state_machine,
+                mut viewport,
+                fit,
+                alignment,
+                fragment,
+                playback,
+            )| {
+                let progress = linear_animation
+                    .as_deref()
+                    .map(RiveLinearAnimation::progress);
+                let mut renderer = rive_rs::Renderer::default();
+                let mut scene = get_scene_or!(
+                    return,
+                    linear_animation.as_mut().map(Mut::reborrow),
+                    state_machine
+                );
+
+                viewport.fit(
+                    Vec2::new(scene.width(), scene.height()),
+                    fit.copied().unwrap_or_default(),
+                    alignment.copied().unwrap_or_default(),
+                );
+                let is_dirty = viewport.take_dirty();
+                let elapsed = playback.map_or(virtual_delta, |playback| {
+                    playback.delta(virtual_delta, real_delta)
+                });
+
+                par_commands.command_scope(|mut commands| {
+                    // Fragments are only rendered on the frames they are (re)inserted.
+                    if scene.advance_and_maybe_draw(&mut renderer, elapsed, &mut viewport) {
+                        commands
+                            .entity(entity)
+                            .insert(VelloFragment(Arc::new(renderer.into_scene())));
+                    } else if let Some(fragment) = fragment.filter(|_| is_dirty) {
+                        commands
+                            .entity(entity)
+                            .insert(VelloFragment(fragment.0.clone()));
+                    }
+                });
 
-            par_commands.command_scope(|mut commands| {
-                if scene.advance_and_maybe_draw(&mut renderer, elapsed, &mut viewport) {
-                    commands
-                        .entity(entity)
-                        .insert(VelloFragment(Arc::new(renderer.into_scene())));
-                } else {
-                    commands.entity(entity).remove::<VelloFragment>();
+                drop(scene);
+
+                let (Some(progress), Some(linear_animation)) = (progress, linear_animation) else {
+                    return;
+                };
+
+                let (finished, looped) = linear_animation.progress_since(progress);
+                if finished || looped {
+                    let name = rive_rs::Scene::name(&linear_animation.0).to_string();
+
+                    par_commands.command_scope(|mut commands| {
+                        commands.add(move |world: &mut World| {
+                            if finished {
+                                world.send_event(RiveAnimationFinished { entity, name });
+                            } else {
+                                world.send_event(RiveAnimationLooped { entity, name });
+                            }
+                        });
+                    });
                 }
-            });
-        });
+            },
+        );
 }
 
 fn send_generic_events(
@@ -331,33 +729,72 @@ pub struct RivePlugin;
 impl Plugin for RivePlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<Riv>()
+            .init_asset::<assets::RivArtboard>()
+            .init_asset::<assets::RivLinearAnimation>()
+            .init_asset::<assets::RivStateMachine>()
             .init_asset_loader::<RivLoader>()
+            .init_asset::<RiveLocaleTable>()
+            .init_asset_loader::<RiveLocaleTableLoader>()
             .init_resource::<RivEntities>()
+            .init_resource::<TouchPolicy>()
+            .init_resource::<PointerCaptures>()
             .add_event::<Input>()
+            .add_event::<RivePointerEvent>()
             .add_event::<GenericEvent>()
+            .add_event::<RiveError>()
+            .add_event::<RiveAnimationFinished>()
+            .add_event::<RiveAnimationLooped>()
+            .add_event::<RiveMissingTranslation>()
             .add_systems(
                 PreUpdate,
                 (
+                    insert_scenes_from_assets,
+                    (
+                        reinstantiate_linear_animations,
+                        instantiate_linear_animations,
+                        instantiate_state_machines,
+                    ),
+                    update_target_images,
                     (insert_deafult_viewports, resize_viewports).chain(),
-                    reinstantiate_linear_animations,
-                    instantiate_linear_animations,
-                    instantiate_state_machines,
-                ),
+                )
+                    .chain(),
             )
             .add_systems(
                 Update,
                 (
-                    pointer_events::pass,
+                    (
+                        pointer_events::pass.run_if(pointer_events::pointer_inputs_exist),
+                        pointer_events::pass_synthetic,
+                    )
+                        .chain()
+                        .in_set(PointerEventsSet),
                     pass_state_machine_input_events,
+                    (
+                        bindings::sync_input_bindings,
+                        inputs::apply_inputs,
+                        localization::localize_text,
+                        text::sync_text_bindings,
+                        text::apply_text,
+                    )
+                        .chain()
+                        .in_set(RiveApplySet),
                     send_generic_events,
                     render_rive_scenes,
+                    inputs::mirror_input_values,
                 )
                     .chain(),
             )
+            .add_systems(PostUpdate, resize_ui_images.after(UiSystem::Layout))
             .add_plugins(ExtractComponentPlugin::<VelloScene>::default());
     }
 
     fn finish(&self, app: &mut App) {
+        #[cfg(feature = "cpu")]
+        if cpu::is_selected(app) {
+            cpu::build(app);
+            return;
+        }
+
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
@@ -1,5 +1,10 @@
 use bevy::{
-    input::{mouse::MouseButtonInput, ButtonState},
+    ecs::system::SystemParam,
+    input::{
+        mouse::MouseButtonInput,
+        touch::{TouchInput, TouchPhase},
+        ButtonState,
+    },
     math::Vec3A,
     prelude::*,
     render::{
@@ -7,13 +12,142 @@ use bevy::{
         render_resource::Face,
         view::RenderLayers,
     },
+    ui::UiStack,
+    utils::{HashMap, HashSet},
 };
 
 use crate::{
-    components::{MeshEntity, RiveLinearAnimation, RiveStateMachine, SpriteEntity, Viewport},
+    components::{
+        MeshEntity, MeshFaces, RiveLinearAnimation, RiveStateMachine, SpriteEntity, Viewport,
+    },
+    events::{PointerAction, PointerSpace, RivePointerEvent},
     plugin::get_scene_or,
+    ui::RiveUiNode,
 };
 
+/// Rive has no dedicated exit event, so the pointer is moved far outside of
+/// the artboard instead, which makes every listener see it leave.
+const EXIT_POSITION: Vec2 = Vec2::splat(-1.0e6);
+
+/// How simultaneous touches on the same scene are passed to it.
+///
+/// Rive scenes only track a single pointer, so every finger landing on a
+/// scene is either ignored or folded into that pointer.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Resource)]
+pub enum TouchPolicy {
+    /// Only the first finger that touches a scene drives it until lifted.
+    #[default]
+    First,
+    /// Every new finger takes the scene over from the previous one.
+    Latest,
+    /// All fingers are passed to the scene as one interleaved pointer.
+    All,
+}
+
+fn pass_touch(scene: &mut dyn rive_rs::Scene, phase: TouchPhase, pos: Vec2, viewport: &Viewport) {
+    match phase {
+        TouchPhase::Started => {
+            scene.pointer_move(pos.x, pos.y, viewport);
+            scene.pointer_down(pos.x, pos.y, viewport);
+        }
+        TouchPhase::Moved => scene.pointer_move(pos.x, pos.y, viewport),
+        TouchPhase::Ended | TouchPhase::Canceled => {
+            scene.pointer_up(pos.x, pos.y, viewport);
+            // Fingers do not hover, so reset hover states once lifted.
+            scene.pointer_move(EXIT_POSITION.x, EXIT_POSITION.y, viewport);
+        }
+    }
+}
+
+/// A pointer input that was captured by a scene.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointerInput {
+    CursorMoved {
+        window: Entity,
+        position: Vec2,
+    },
+    MouseButton {
+        window: Entity,
+        button: MouseButton,
+        state: ButtonState,
+    },
+    Touch {
+        id: u64,
+        phase: TouchPhase,
+        position: Vec2,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerCapture {
+    pub scene: Entity,
+    pub input: PointerInput,
+    /// Whether the scene is a state machine and can thus react to the input.
+    ///
+    /// Rive does not report whether a listener was actually hit, so this is
+    /// `true` for any input landing inside of a state machine's bounds.
+    pub interactive: bool,
+}
+
+/// The pointer inputs captured by Rive scenes during the current frame.
+///
+/// Gameplay systems running after [`PointerEventsSet`] can use it to ignore
+/// clicks and touches that landed on Rive UI.
+#[derive(Debug, Default, Resource)]
+pub struct PointerCaptures {
+    captures: Vec<PointerCapture>,
+    hovered: Option<Entity>,
+}
+
+impl PointerCaptures {
+    pub fn iter(&self) -> impl Iterator<Item = &PointerCapture> {
+        self.captures.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.captures.is_empty()
+    }
+
+    /// Returns the scene that captured a `button` press or release, if any.
+    pub fn mouse_button(&self, button: MouseButton) -> Option<&PointerCapture> {
+        self.captures.iter().find(|capture| {
+            matches!(capture.input, PointerInput::MouseButton { button: b, .. } if b == button)
+        })
+    }
+
+    /// Returns the scene that captured the finger `id`, if any.
+    pub fn touch(&self, id: u64) -> Option<&PointerCapture> {
+        self.captures
+            .iter()
+            .find(|capture| matches!(capture.input, PointerInput::Touch { id: i, .. } if i == id))
+    }
+
+    /// Returns the top-most scene under the cursor, if any.
+    pub fn hovered(&self) -> Option<Entity> {
+        self.hovered
+    }
+}
+
+#[derive(SystemSet, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PointerEventsSet;
+
+#[derive(Clone, Copy, Debug)]
+struct CaptureTarget {
+    scene: Entity,
+    interactive: bool,
+}
+
+fn store_touch_owner(
+    touch_owners: &mut HashMap<Entity, u64>,
+    entity: Entity,
+    touch_owner: Option<u64>,
+) {
+    match touch_owner {
+        Some(id) => touch_owners.insert(entity, id),
+        None => touch_owners.remove(&entity),
+    };
+}
+
 #[derive(Debug)]
 enum CameraType {
     Camera2d,
@@ -22,21 +156,43 @@ enum CameraType {
 
 fn get_filter_map_for_sprite(
     image_dimensions: Vec2,
-    transform: Transform,
-) -> impl Fn(Vec2) -> Option<Vec2> + Copy {
-    let scaled_image_dimension = image_dimensions * transform.scale.truncate();
-    let bounding_box =
-        Rect::from_center_size(transform.translation.truncate(), scaled_image_dimension);
-
-    move |world_pos| {
-        bounding_box.contains(world_pos).then(|| {
-            let mut pos: Vec2 = world_pos - bounding_box.min;
-            // Flip y since the Y axis points down in Rive.
-            pos.y = bounding_box.height() - pos.y;
-            pos /= transform.scale.truncate();
-
-            pos
-        })
+    sprite: &Sprite,
+    global_transform: &GlobalTransform,
+) -> impl Fn(Ray) -> Option<Vec2> + Copy {
+    let image_rect = sprite
+        .rect
+        .unwrap_or(Rect::from_corners(Vec2::ZERO, image_dimensions));
+    let size = sprite.custom_size.unwrap_or(image_rect.size());
+    let anchor = sprite.anchor.as_vec();
+    let (flip_x, flip_y) = (sprite.flip_x, sprite.flip_y);
+    let world_to_local = global_transform.affine().inverse();
+
+    move |ray| {
+        // Intersect the ray with the sprite's plane in its local space.
+        let origin = world_to_local.transform_point3(ray.origin);
+        let direction = world_to_local.transform_vector3(ray.direction);
+
+        if direction.z.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let local_pos = (origin - direction * (origin.z / direction.z)).truncate();
+        let mut uv = local_pos / size + Vec2::splat(0.5) + anchor;
+
+        if !(0.0..=1.0).contains(&uv.x) || !(0.0..=1.0).contains(&uv.y) {
+            return None;
+        }
+
+        if flip_x {
+            uv.x = 1.0 - uv.x;
+        }
+
+        // Flip y since the Y axis points down in Rive.
+        if !flip_y {
+            uv.y = 1.0 - uv.y;
+        }
+
+        Some(image_rect.min + uv * image_rect.size())
     }
 }
 
@@ -44,33 +200,65 @@ fn get_filter_map_for_sprite(
 struct PointerEventPasser<'e> {
     cursor_moved_events: Vec<&'e CursorMoved>,
     mouse_button_input_events: Vec<&'e MouseButtonInput>,
+    touch_input_events: Vec<&'e TouchInput>,
+    touch_policy: TouchPolicy,
+    captures: Vec<PointerCapture>,
 }
 
 impl<'e> PointerEventPasser<'e> {
     pub fn new(
         cursor_moved_events: &'e mut EventReader<CursorMoved>,
         mouse_button_input_events: &'e mut EventReader<MouseButtonInput>,
+        touch_input_events: &'e mut EventReader<TouchInput>,
+        touch_policy: TouchPolicy,
     ) -> Self {
         Self {
             cursor_moved_events: cursor_moved_events.read().collect(),
             mouse_button_input_events: mouse_button_input_events.read().collect(),
+            touch_input_events: touch_input_events.read().collect(),
+            touch_policy,
+            captures: Vec::new(),
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.cursor_moved_events.is_empty() && self.mouse_button_input_events.is_empty()
+        self.cursor_moved_events.is_empty()
+            && self.mouse_button_input_events.is_empty()
+            && self.touch_input_events.is_empty()
     }
 
+    /// Passes all events that `filter_map` places inside of `scene`'s target
+    /// image and returns whether the cursor moved over it.
+    ///
+    /// `touch_owner` is the finger currently driving `scene`, if any.
     pub fn pass<F: Fn(Vec2) -> Option<Vec2> + Copy>(
         &mut self,
         filter_map: F,
         windows: &Query<&Window>,
+        target: CaptureTarget,
         scene: &mut dyn rive_rs::Scene,
         viewport: &Viewport,
-    ) {
+        touch_owner: &mut Option<u64>,
+    ) -> bool {
+        let filter_map = |pos: Vec2| filter_map(pos).map(|pos| viewport.target_to_viewport(pos));
+        let mut is_hovered = false;
+        let captures = &mut self.captures;
+        let mut capture = |input| {
+            captures.push(PointerCapture {
+                scene: target.scene,
+                input,
+                interactive: target.interactive,
+            })
+        };
+
         self.cursor_moved_events.retain(|cursor_moved| {
             if let Some(pos) = filter_map(cursor_moved.position) {
                 scene.pointer_move(pos.x, pos.y, viewport);
+                is_hovered = true;
+                capture(PointerInput::CursorMoved {
+                    window: cursor_moved.window,
+                    position: cursor_moved.position,
+                });
 
                 false
             } else {
@@ -91,14 +279,66 @@ impl<'e> PointerEventPasser<'e> {
             {
                 match mouse_button_input.state {
                     ButtonState::Pressed => scene.pointer_down(pos.x, pos.y, viewport),
-                    ButtonState::Released => scene.pointer_down(pos.x, pos.y, viewport),
+                    ButtonState::Released => scene.pointer_up(pos.x, pos.y, viewport),
                 }
+                capture(PointerInput::MouseButton {
+                    window: mouse_button_input.window,
+                    button: mouse_button_input.button,
+                    state: mouse_button_input.state,
+                });
 
                 false
             } else {
                 true
             }
         });
+
+        let touch_policy = self.touch_policy;
+        self.touch_input_events.retain(|touch_input| {
+            let Some(pos) = filter_map(touch_input.position) else {
+                return true;
+            };
+
+            let is_owner = match (touch_policy, touch_input.phase) {
+                (TouchPolicy::All, _) | (TouchPolicy::Latest, TouchPhase::Started) => true,
+                (_, TouchPhase::Started) => touch_owner.is_none(),
+                _ => *touch_owner == Some(touch_input.id),
+            };
+
+            if is_owner {
+                // A finger taking the scene over releases the previous one,
+                // outside of the artboard so that it does not count as a click.
+                let takes_over = touch_policy == TouchPolicy::Latest
+                    && touch_input.phase == TouchPhase::Started
+                    && touch_owner.is_some_and(|id| id != touch_input.id);
+                if takes_over {
+                    pass_touch(scene, TouchPhase::Canceled, EXIT_POSITION, viewport);
+                }
+
+                pass_touch(scene, touch_input.phase, pos, viewport);
+
+                *touch_owner = match touch_input.phase {
+                    TouchPhase::Started | TouchPhase::Moved => Some(touch_input.id),
+                    TouchPhase::Ended | TouchPhase::Canceled => None,
+                };
+            }
+
+            // Fingers driving another scene are left to it, so that it sees them lift.
+            if !is_owner && touch_input.phase != TouchPhase::Started {
+                return true;
+            }
+
+            // New touches over a scene never reach the scenes behind it, even when ignored.
+            capture(PointerInput::Touch {
+                id: touch_input.id,
+                phase: touch_input.phase,
+                position: touch_input.position,
+            });
+
+            false
+        });
+
+        is_hovered
     }
 }
 
@@ -154,12 +394,12 @@ impl Triangle {
 #[derive(Debug)]
 struct Triangles<'m> {
     mesh: &'m Mesh,
-    transform: &'m Transform,
+    transform: &'m GlobalTransform,
     i: usize,
 }
 
 impl<'m> Triangles<'m> {
-    pub fn new(mesh: &'m Mesh, transform: &'m Transform) -> Self {
+    pub fn new(mesh: &'m Mesh, transform: &'m GlobalTransform) -> Self {
         Self {
             mesh,
             transform,
@@ -206,6 +446,46 @@ impl Iterator for Triangles<'_> {
     }
 }
 
+/// Returns `true` if the window and input events read by [`pass`] exist,
+/// which is not the case in headless apps without those plugins.
+pub(crate) fn pointer_inputs_exist(
+    cursor_moved_events: Option<Res<Events<CursorMoved>>>,
+    cursor_left_events: Option<Res<Events<CursorLeft>>>,
+    mouse_button_input_events: Option<Res<Events<MouseButtonInput>>>,
+    touch_input_events: Option<Res<Events<TouchInput>>>,
+) -> bool {
+    cursor_moved_events.is_some()
+        && cursor_left_events.is_some()
+        && mouse_button_input_events.is_some()
+        && touch_input_events.is_some()
+}
+
+/// The window and input events passed to scenes by [`pass`].
+#[derive(SystemParam)]
+pub struct PointerInputEvents<'w, 's> {
+    cursor_moved: EventReader<'w, 's, CursorMoved>,
+    cursor_left: EventReader<'w, 's, CursorLeft>,
+    mouse_button_input: EventReader<'w, 's, MouseButtonInput>,
+    touch_input: EventReader<'w, 's, TouchInput>,
+}
+
+/// The [`RiveUiNode`]s that can receive pointer input, and how to order them.
+#[derive(SystemParam)]
+pub struct RiveUiNodes<'w, 's> {
+    nodes: Query<
+        'w,
+        's,
+        (
+            &'static Node,
+            &'static GlobalTransform,
+            &'static Interaction,
+        ),
+        With<RiveUiNode>,
+    >,
+    stack: Option<Res<'w, UiStack>>,
+    scale: Option<Res<'w, UiScale>>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn pass(
     cameras: Query<(
@@ -214,27 +494,33 @@ pub fn pass(
         Option<&Camera2d>,
         Option<&RenderLayers>,
     )>,
-    mut cursor_moved_events: EventReader<CursorMoved>,
-    mut mouse_button_input_events: EventReader<MouseButtonInput>,
+    mut input_events: PointerInputEvents,
+    touch_policy: Res<TouchPolicy>,
     windows: Query<&Window>,
     mut scenes: Query<(
+        Entity,
         Option<&mut RiveLinearAnimation>,
         Option<&mut RiveStateMachine>,
         &Handle<Image>,
-        &SpriteEntity,
-        &MeshEntity,
+        Option<&SpriteEntity>,
+        Option<&MeshEntity>,
         &Viewport,
     )>,
     image_assets: Res<Assets<Image>>,
-    sprites: Query<(&Transform, Option<&RenderLayers>), With<Sprite>>,
+    ui_nodes: RiveUiNodes,
+    sprites: Query<(&Sprite, &GlobalTransform, Option<&RenderLayers>)>,
     meshes: Query<(
-        &Transform,
+        &GlobalTransform,
         &Handle<Mesh>,
-        &Handle<StandardMaterial>,
+        Option<&MeshFaces>,
+        Option<&Handle<StandardMaterial>>,
         Option<&RenderLayers>,
     )>,
-    mesh_assets: Res<Assets<Mesh>>,
-    material_assets: Res<Assets<StandardMaterial>>,
+    mesh_assets: Option<Res<Assets<Mesh>>>,
+    material_assets: Option<Res<Assets<StandardMaterial>>>,
+    mut pointer_captures: ResMut<PointerCaptures>,
+    mut hovered: Local<HashSet<Entity>>,
+    mut touch_owners: Local<HashMap<Entity, u64>>,
 ) {
     let mut cameras: Vec<_> = cameras
         .iter()
@@ -253,24 +539,105 @@ pub fn pass(
         .collect();
     cameras.sort_by_key(|t| t.0.order);
 
-    let mut passer =
-        PointerEventPasser::new(&mut cursor_moved_events, &mut mouse_button_input_events);
+    let mut passer = PointerEventPasser::new(
+        &mut input_events.cursor_moved,
+        &mut input_events.mouse_button_input,
+        &mut input_events.touch_input,
+        *touch_policy,
+    );
+    let last_cursor_moved = passer.cursor_moved_events.last().copied();
+    let cursor_moved = last_cursor_moved.is_some();
+    let cursor_left = input_events.cursor_left.read().count() > 0;
+    let mut hovered_now = HashSet::new();
+    let ui_scale = ui_nodes.scale.map_or(1.0, |ui_scale| ui_scale.0 as f32);
+    let ui_stack = ui_nodes
+        .stack
+        .as_deref()
+        .map_or(&[][..], |ui_stack| ui_stack.uinodes.as_slice());
+
+    // UI nodes are drawn on top of everything else, so they get the first pick,
+    // starting with the top-most one.
+    for &entity in ui_stack.iter().rev() {
+        if passer.is_empty() {
+            break;
+        }
+
+        let Ok((node, global_transform, interaction)) = ui_nodes.nodes.get(entity) else {
+            continue;
+        };
+
+        // bevy_ui resets a pressed node to `Interaction::None` on the frame its
+        // finger lifts, so the node's own finger is still passed to it then.
+        let mut touch_owner = touch_owners.get(&entity).copied();
+        let rect = node.logical_rect(global_transform);
+        if (*interaction == Interaction::None && touch_owner.is_none()) || rect.is_empty() {
+            continue;
+        }
+
+        let Ok((_, linear_animation, state_machine, image_handle, .., viewport)) =
+            scenes.get_mut(entity)
+        else {
+            continue;
+        };
+
+        let target = CaptureTarget {
+            scene: entity,
+            interactive: state_machine.is_some(),
+        };
+        let mut scene = get_scene_or!(continue, linear_animation, state_machine);
+        let Some(image) = image_assets.get(image_handle) else {
+            continue;
+        };
+        let image_dimensions = image.size().as_vec2();
+
+        if passer.pass(
+            |pos| {
+                let pos = pos / ui_scale;
+                rect.contains(pos)
+                    .then(|| (pos - rect.min) / rect.size() * image_dimensions)
+            },
+            &windows,
+            target,
+            &mut *scene,
+            viewport,
+            &mut touch_owner,
+        ) {
+            hovered_now.insert(entity);
+        }
+
+        store_touch_owner(&mut touch_owners, entity, touch_owner);
+    }
 
     for (camera, camera_transform, camera_type, camera_render_layers) in cameras {
-        for (linear_animation, state_machine, image_handle, sprite_entity, mesh_entity, viewport) in
-            &mut scenes
+        for (
+            entity,
+            linear_animation,
+            state_machine,
+            image_handle,
+            sprite_entity,
+            mesh_entity,
+            viewport,
+        ) in &mut scenes
         {
             if passer.is_empty() {
                 break;
             }
 
+            let target = CaptureTarget {
+                scene: entity,
+                interactive: state_machine.is_some(),
+            };
             let mut scene = get_scene_or!(continue, linear_animation, state_machine);
-            let image_dimensions = image_assets.get(image_handle).unwrap().size().as_vec2();
+            let Some(image) = image_assets.get(image_handle) else {
+                continue;
+            };
+            let image_dimensions = image.size().as_vec2();
+            let mut touch_owner = touch_owners.get(&entity).copied();
 
             match camera_type {
                 CameraType::Camera2d => {
-                    let Some((transform, render_layers)) = sprite_entity
-                        .entity
+                    let Some((sprite, global_transform, render_layers)) = sprite_entity
+                        .and_then(|sprite_entity| sprite_entity.entity)
                         .and_then(|entity| sprites.get(entity).ok())
                     else {
                         continue;
@@ -282,34 +649,57 @@ pub fn pass(
                         continue;
                     }
 
-                    passer.pass(
+                    let filter_map =
+                        get_filter_map_for_sprite(image_dimensions, sprite, global_transform);
+
+                    if passer.pass(
                         |pos| {
                             camera
                                 .viewport_to_world(camera_transform, pos)
-                                .map(|ray| ray.origin.truncate())
-                                .and_then(get_filter_map_for_sprite(image_dimensions, *transform))
+                                .and_then(filter_map)
                         },
                         &windows,
+                        target,
                         &mut *scene,
                         viewport,
-                    );
+                        &mut touch_owner,
+                    ) {
+                        hovered_now.insert(entity);
+                    }
                 }
                 CameraType::Camera3d => {
-                    let Some((transform, mesh_handle, material_handle, render_layers)) =
+                    let Some((transform, mesh_handle, faces, material_handle, render_layers)) =
                         mesh_entity
-                            .entity
+                            .and_then(|mesh_entity| mesh_entity.entity)
                             .and_then(|entity| meshes.get(entity).ok())
                     else {
                         continue;
                     };
 
-                    let Some(mesh) = mesh_assets.get(mesh_handle) else {
+                    let Some(mesh) = mesh_assets
+                        .as_ref()
+                        .and_then(|mesh_assets| mesh_assets.get(mesh_handle))
+                    else {
                         continue;
                     };
 
-                    let Some(material) = material_assets.get(material_handle) else {
-                        continue;
+                    let material_cull_mode = match material_handle {
+                        Some(material_handle) => {
+                            let Some(material) = material_assets
+                                .as_ref()
+                                .and_then(|material_assets| material_assets.get(material_handle))
+                            else {
+                                continue;
+                            };
+
+                            Some(material.cull_mode)
+                        }
+                        None => None,
                     };
+                    let cull_mode = faces
+                        .copied()
+                        .unwrap_or_default()
+                        .cull_mode(material_cull_mode);
 
                     if !camera_render_layers
                         .intersects(&render_layers.copied().unwrap_or(RenderLayers::all()))
@@ -322,22 +712,262 @@ pub fn pass(
                             break;
                         }
 
-                        passer.pass(
+                        if passer.pass(
                             |pos| {
                                 camera
                                     .viewport_to_world(camera_transform, pos)
-                                    .and_then(|ray| {
-                                        triangle.intersect_to_mesh_uv(ray, material.cull_mode)
-                                    })
+                                    .and_then(|ray| triangle.intersect_to_mesh_uv(ray, cull_mode))
                                     .map(|pos| pos * image_dimensions)
                             },
                             &windows,
+                            target,
                             &mut *scene,
                             viewport,
-                        );
+                            &mut touch_owner,
+                        ) {
+                            hovered_now.insert(entity);
+                        }
                     }
                 }
             }
+
+            store_touch_owner(&mut touch_owners, entity, touch_owner);
         }
     }
+
+    let mut captures = passer.captures;
+
+    // Fingers that were dragged off of the scene they are driving.
+    for touch_input in passer.touch_input_events {
+        let Some(entity) = touch_owners
+            .iter()
+            .find_map(|(entity, id)| (*id == touch_input.id).then_some(*entity))
+        else {
+            continue;
+        };
+
+        let Ok((_, linear_animation, state_machine, .., viewport)) = scenes.get_mut(entity) else {
+            touch_owners.remove(&entity);
+            continue;
+        };
+
+        captures.push(PointerCapture {
+            scene: entity,
+            input: PointerInput::Touch {
+                id: touch_input.id,
+                phase: touch_input.phase,
+                position: touch_input.position,
+            },
+            interactive: state_machine.is_some(),
+        });
+
+        let mut scene = get_scene_or!(continue, linear_animation, state_machine);
+        pass_touch(&mut *scene, touch_input.phase, EXIT_POSITION, viewport);
+
+        if matches!(touch_input.phase, TouchPhase::Ended | TouchPhase::Canceled) {
+            touch_owners.remove(&entity);
+        }
+    }
+
+    if cursor_left {
+        pointer_captures.hovered = None;
+    } else if let Some(cursor_moved) = last_cursor_moved {
+        pointer_captures.hovered = captures.iter().find_map(|capture| {
+            let is_last = capture.input
+                == PointerInput::CursorMoved {
+                    window: cursor_moved.window,
+                    position: cursor_moved.position,
+                };
+
+            is_last.then_some(capture.scene)
+        });
+    }
+
+    pointer_captures.captures = captures;
+
+    if cursor_left {
+        hovered_now.clear();
+    } else if !cursor_moved {
+        return;
+    }
+
+    for entity in hovered.difference(&hovered_now) {
+        let Ok((_, linear_animation, state_machine, .., viewport)) = scenes.get_mut(*entity)
+        else {
+            continue;
+        };
+
+        let mut scene = get_scene_or!(continue, linear_animation, state_machine);
+        scene.pointer_move(EXIT_POSITION.x, EXIT_POSITION.y, viewport);
+    }
+
+    *hovered = hovered_now;
+}
+
+pub fn pass_synthetic(
+    mut pointer_events: EventReader<RivePointerEvent>,
+    mut scenes: Query<(
+        Option<&mut RiveLinearAnimation>,
+        Option<&mut RiveStateMachine>,
+        &Viewport,
+    )>,
+) {
+    for pointer_event in pointer_events.read() {
+        let Ok((linear_animation, state_machine, viewport)) = scenes.get_mut(pointer_event.scene)
+        else {
+            continue;
+        };
+
+        let mut scene = get_scene_or!(continue, linear_animation, state_machine);
+
+        let pos = match pointer_event.space {
+            PointerSpace::Image => viewport.target_to_viewport(pointer_event.position),
+            PointerSpace::Artboard => viewport.artboard_to_viewport(
+                Vec2::new(scene.width(), scene.height()),
+                pointer_event.position,
+            ),
+        };
+
+        match pointer_event.action {
+            PointerAction::Down => scene.pointer_down(pos.x, pos.y, viewport),
+            PointerAction::Up => scene.pointer_up(pos.x, pos.y, viewport),
+            PointerAction::Move => scene.pointer_move(pos.x, pos.y, viewport),
+            PointerAction::Exit => scene.pointer_move(EXIT_POSITION.x, EXIT_POSITION.y, viewport),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::sprite::Anchor;
+
+    use super::*;
+
+    const IMAGE_DIMENSIONS: Vec2 = Vec2::new(100.0, 50.0);
+
+    /// Returns where a ray shot straight down the Z axis at `world_pos` hits
+    /// the sprite's image.
+    fn hit(sprite: Sprite, transform: Transform, world_pos: Vec2) -> Option<Vec2> {
+        let filter_map = get_filter_map_for_sprite(IMAGE_DIMENSIONS, &sprite, &transform.into());
+
+        filter_map(Ray {
+            origin: world_pos.extend(10.0),
+            direction: Vec3::NEG_Z,
+        })
+    }
+
+    fn assert_hits(actual: Option<Vec2>, expected: Vec2) {
+        let actual = actual.expect("ray should hit the sprite");
+        assert!(
+            actual.abs_diff_eq(expected, 1.0e-4),
+            "{actual} is not {expected}"
+        );
+    }
+
+    #[test]
+    fn sprite_center_and_corner() {
+        let sprite = Sprite::default();
+
+        assert_hits(
+            hit(sprite.clone(), default(), Vec2::ZERO),
+            Vec2::new(50.0, 25.0),
+        );
+        assert_hits(
+            hit(sprite, default(), Vec2::new(-40.0, 20.0)),
+            Vec2::new(10.0, 5.0),
+        );
+    }
+
+    #[test]
+    fn sprite_miss() {
+        assert_eq!(
+            hit(Sprite::default(), default(), Vec2::new(60.0, 0.0)),
+            None
+        );
+        assert_eq!(
+            hit(Sprite::default(), default(), Vec2::new(0.0, -30.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn sprite_parallel_ray() {
+        let filter_map =
+            get_filter_map_for_sprite(IMAGE_DIMENSIONS, &Sprite::default(), &default());
+
+        assert_eq!(
+            filter_map(Ray {
+                origin: Vec3::new(0.0, 0.0, 10.0),
+                direction: Vec3::X,
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn sprite_anchor() {
+        let sprite = Sprite {
+            anchor: Anchor::BottomLeft,
+            ..default()
+        };
+
+        assert_hits(
+            hit(sprite.clone(), default(), Vec2::new(10.0, 5.0)),
+            Vec2::new(10.0, 45.0),
+        );
+        assert_eq!(hit(sprite, default(), Vec2::new(-10.0, 5.0)), None);
+    }
+
+    #[test]
+    fn sprite_flip() {
+        let pos = Vec2::new(-40.0, 20.0);
+        let flip_x = Sprite {
+            flip_x: true,
+            ..default()
+        };
+        let flip_y = Sprite {
+            flip_y: true,
+            ..default()
+        };
+
+        assert_hits(hit(flip_x, default(), pos), Vec2::new(90.0, 5.0));
+        assert_hits(hit(flip_y, default(), pos), Vec2::new(10.0, 45.0));
+    }
+
+    #[test]
+    fn sprite_custom_size_and_rect() {
+        let custom_size = Sprite {
+            custom_size: Some(Vec2::new(200.0, 100.0)),
+            ..default()
+        };
+        let rect = Sprite {
+            rect: Some(Rect::new(50.0, 0.0, 100.0, 50.0)),
+            ..default()
+        };
+
+        assert_hits(
+            hit(custom_size, default(), Vec2::new(-80.0, 40.0)),
+            Vec2::new(10.0, 5.0),
+        );
+        assert_hits(
+            hit(rect.clone(), default(), Vec2::ZERO),
+            Vec2::new(75.0, 25.0),
+        );
+        assert_eq!(hit(rect, default(), Vec2::new(30.0, 0.0)), None);
+    }
+
+    #[test]
+    fn sprite_transform() {
+        let rotated = Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let moved_and_scaled = Transform::from_xyz(100.0, 0.0, 0.0).with_scale(Vec3::splat(2.0));
+
+        assert_hits(
+            hit(Sprite::default(), rotated, Vec2::new(0.0, 20.0)),
+            Vec2::new(70.0, 25.0),
+        );
+        assert_hits(
+            hit(Sprite::default(), moved_and_scaled, Vec2::new(20.0, 40.0)),
+            Vec2::new(10.0, 5.0),
+        );
+    }
 }
@@ -0,0 +1,302 @@
+//! Golden-image snapshot testing of `.riv` files.
+//!
+//! A [`Snapshot`] runs a minimal headless [`App`] with [`RivePlugin`] on the
+//! CPU backend, advances a single scene by fixed time steps and compares its
+//! target [`Image`] against a PNG golden.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use bevy::prelude::*;
+//! use rive_bevy::{snapshot::Snapshot, LinearAnimation};
+//!
+//! let mut snapshot = Snapshot::new("marty.riv", UVec2::splat(256), |riv| LinearAnimation {
+//!     riv,
+//!     ..default()
+//! })
+//! .unwrap();
+//!
+//! snapshot.advance(Duration::from_millis(500));
+//! snapshot.assert_matches("marty-500ms");
+//! ```
+//!
+//! Goldens live in `tests/goldens` under the crate being tested. Set
+//! `RIVE_UPDATE_SNAPSHOTS=1` to (re)write them from the current output.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    asset::AssetPath,
+    prelude::*,
+    render::render_resource::Extent3d,
+    time::TimeUpdateStrategy,
+    utils::thiserror::{self, Error},
+};
+use image::{Rgba, RgbaImage};
+
+use crate::{
+    Riv, RiveLinearAnimation, RivePlugin, RiveRenderBackend, RiveStateMachine, SceneTarget,
+};
+
+const UPDATE_ENV_VAR: &str = "RIVE_UPDATE_SNAPSHOTS";
+const LOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// The scene was not instantiated before the load timeout.
+    #[error("scene from {0:?} could not be instantiated in time")]
+    LoadTimeout(String),
+    /// No golden exists yet; the current output was written next to it.
+    #[error("golden {0:?} does not exist; set RIVE_UPDATE_SNAPSHOTS=1 to create it")]
+    MissingGolden(PathBuf),
+    /// The golden and the current output have different dimensions.
+    #[error("golden is {expected:?} but the rendered image is {actual:?}")]
+    SizeMismatch { expected: UVec2, actual: UVec2 },
+    /// Too many pixels differ from the golden; a diff image was written.
+    #[error(
+        "{differing_pixels} pixels differ from the golden \
+         (max channel difference {max_difference}); see {diff:?}"
+    )]
+    Mismatch {
+        differing_pixels: usize,
+        max_difference: u8,
+        diff: PathBuf,
+    },
+    /// An [Image](image) Error.
+    #[error("could not read or write PNG: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// How far a rendered image may stray from its golden.
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    /// Maximum per-channel difference for two pixels to be considered equal.
+    pub channel: u8,
+    /// Fraction of pixels, in `0.0..=1.0`, allowed to differ.
+    pub pixels: f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channel: 2,
+            pixels: 0.001,
+        }
+    }
+}
+
+pub struct Snapshot {
+    app: App,
+    image: Handle<Image>,
+    goldens: PathBuf,
+    tolerance: Tolerance,
+}
+
+impl Snapshot {
+    /// Loads the `.riv` at `path`, spawns the scene returned by `scene` with a
+    /// `size` target image and waits until it is instantiated.
+    pub fn new<'a, B: Bundle>(
+        path: impl Into<AssetPath<'a>>,
+        size: UVec2,
+        scene: impl FnOnce(Handle<Riv>) -> B,
+    ) -> Result<Self, SnapshotError> {
+        let mut app = App::new();
+
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .insert_resource(RiveRenderBackend::Cpu)
+            .add_plugins(RivePlugin);
+        app.finish();
+        app.cleanup();
+
+        // Fixed steps bigger than the default cap must not get clamped.
+        app.world
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::MAX);
+
+        let path = path.into();
+        let riv = app.world.resource::<AssetServer>().load(path.clone());
+
+        let mut image = Image::default();
+        image.resize(Extent3d {
+            width: size.x,
+            height: size.y,
+            ..default()
+        });
+        let image = app.world.resource_mut::<Assets<Image>>().add(image);
+
+        let entity = app
+            .world
+            .spawn(scene(riv))
+            .insert(SceneTarget {
+                image: image.clone(),
+                ..default()
+            })
+            .id();
+
+        let start = Instant::now();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+
+        loop {
+            app.update();
+
+            let scene = app.world.entity(entity);
+            if scene.contains::<RiveStateMachine>() || scene.contains::<RiveLinearAnimation>() {
+                break;
+            }
+
+            if start.elapsed() > LOAD_TIMEOUT {
+                return Err(SnapshotError::LoadTimeout(path.to_string()));
+            }
+
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let goldens = env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default()
+            .join("tests")
+            .join("goldens");
+
+        Ok(Self {
+            app,
+            image,
+            goldens,
+            tolerance: Tolerance::default(),
+        })
+    }
+
+    /// Sets the directory where goldens are read from and written to.
+    pub fn with_goldens(mut self, goldens: impl Into<PathBuf>) -> Self {
+        self.goldens = goldens.into();
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn app(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Runs a single frame that advances the scene by `delta`.
+    pub fn advance(&mut self, delta: Duration) -> &mut Self {
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+        self.app.update();
+        self
+    }
+
+    /// Runs `frames` frames of `delta` each.
+    pub fn advance_frames(&mut self, frames: usize, delta: Duration) -> &mut Self {
+        for _ in 0..frames {
+            self.advance(delta);
+        }
+        self
+    }
+
+    /// Returns the current content of the scene's target image.
+    pub fn image(&self) -> RgbaImage {
+        let image = self
+            .app
+            .world
+            .resource::<Assets<Image>>()
+            .get(&self.image)
+            .expect("snapshot target image was removed");
+        let size = image.size();
+
+        RgbaImage::from_raw(size.x, size.y, image.data.clone())
+            .expect("snapshot target image must be 4 bytes per pixel")
+    }
+
+    /// Compares the target image against the golden called `name`.
+    pub fn compare(&self, name: &str) -> Result<(), SnapshotError> {
+        let actual = self.image();
+        let golden = self.goldens.join(format!("{name}.png"));
+
+        std::fs::create_dir_all(&self.goldens).map_err(image::ImageError::IoError)?;
+
+        if env::var_os(UPDATE_ENV_VAR).is_some_and(|val| val != "0") {
+            actual.save(&golden)?;
+
+            return Ok(());
+        }
+
+        if !golden.exists() {
+            save_next_to(&actual, &golden, "new")?;
+
+            return Err(SnapshotError::MissingGolden(golden));
+        }
+
+        let expected = image::open(&golden)?.to_rgba8();
+        if expected.dimensions() != actual.dimensions() {
+            return Err(SnapshotError::SizeMismatch {
+                expected: expected.dimensions().into(),
+                actual: actual.dimensions().into(),
+            });
+        }
+
+        let mut diff = RgbaImage::new(expected.width(), expected.height());
+        let mut differing_pixels = 0;
+        let mut max_difference = 0;
+
+        for ((expected, actual), diff) in expected
+            .pixels()
+            .zip(actual.pixels())
+            .zip(diff.pixels_mut())
+        {
+            let difference = expected
+                .0
+                .iter()
+                .zip(actual.0)
+                .map(|(&e, a)| e.abs_diff(a))
+                .max()
+                .unwrap_or_default();
+            max_difference = max_difference.max(difference);
+
+            *diff = if difference > self.tolerance.channel {
+                differing_pixels += 1;
+                Rgba([255, 0, 0, 255])
+            } else {
+                // Keep a faint copy of the golden to give the red pixels context.
+                let [r, g, b, a] = expected.0;
+                let luma = ((r as u16 + g as u16 + b as u16) / 3 * a as u16 / 255) as u8;
+                Rgba([luma / 4, luma / 4, luma / 4, 255])
+            };
+        }
+
+        let allowed = (self.tolerance.pixels * (diff.width() * diff.height()) as f32) as usize;
+        if differing_pixels > allowed {
+            save_next_to(&actual, &golden, "actual")?;
+            let diff_path = save_next_to(&diff, &golden, "diff")?;
+
+            return Err(SnapshotError::Mismatch {
+                differing_pixels,
+                max_difference,
+                diff: diff_path,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Like [`Snapshot::compare`], but panics with the error on mismatch.
+    #[track_caller]
+    pub fn assert_matches(&self, name: &str) {
+        if let Err(error) = self.compare(name) {
+            panic!("snapshot {name:?} failed: {error}");
+        }
+    }
+}
+
+fn save_next_to(image: &RgbaImage, golden: &Path, suffix: &str) -> Result<PathBuf, SnapshotError> {
+    let path = golden.with_extension(format!("{suffix}.png"));
+    image.save(&path)?;
+
+    Ok(path)
+}
@@ -0,0 +1,227 @@
+use bevy::{
+    prelude::*,
+    utils::{
+        thiserror::{self, Error},
+        HashMap,
+    },
+};
+use rive_rs::components::{NestedArtboard, TextValueRun};
+
+use crate::{
+    bindings::{read_field, sync_bindings, BindingSet},
+    components::{RiveLinearAnimation, RiveStateMachine},
+};
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum RiveTextError {
+    /// The artboard has no text run with this name or path.
+    #[error("text run {0:?} cannot be found")]
+    Unknown(String),
+}
+
+/// Finds the text run called `path`. Runs inside nested artboards are named
+/// by the nested artboards' names followed by the run's, separated by `/`.
+fn find_run(artboard: &mut rive_rs::Artboard, path: &str) -> Option<TextValueRun> {
+    match path.split_once('/') {
+        None => artboard
+            .components()
+            .find(|component| component.name() == path)?
+            .try_into()
+            .ok(),
+        Some((nested, rest)) => {
+            let nested: NestedArtboard = artboard
+                .components()
+                .find(|component| component.name() == nested)?
+                .try_into()
+                .ok()?;
+
+            find_run(&mut nested.artboard(), rest)
+        }
+    }
+}
+
+/// Collects the paths and texts of every run in `artboard` and its nested
+/// artboards.
+pub(crate) fn text_runs(artboard: &mut rive_rs::Artboard) -> HashMap<String, String> {
+    fn collect(artboard: &mut rive_rs::Artboard, prefix: &str, runs: &mut HashMap<String, String>) {
+        for component in artboard.components() {
+            let name = format!("{prefix}{}", component.name());
+
+            if let Ok(run) = TextValueRun::try_from(component) {
+                runs.insert(name, run.text().to_string());
+            }
+        }
+
+        for component in artboard.components() {
+            let name = format!("{prefix}{}/", component.name());
+
+            if let Ok(nested) = NestedArtboard::try_from(component) {
+                collect(&mut nested.artboard(), &name, runs);
+            }
+        }
+    }
+
+    let mut runs = HashMap::new();
+    collect(artboard, "", &mut runs);
+
+    runs
+}
+
+/// The text runs of an entity's scene, keyed by run name.
+///
+/// Inserted by the plugin when the scene is instantiated. Runs inside nested
+/// artboards are keyed by their path, e.g. `Nested/Run`. Texts set here are
+/// applied before the scene is next advanced, in the same frame for systems
+/// that run before [`RiveApplySet`](crate::RiveApplySet).
+#[derive(Component, Debug, Default)]
+pub struct RiveText {
+    texts: HashMap<String, String>,
+    pending: Vec<String>,
+}
+
+impl RiveText {
+    pub(crate) fn new(texts: HashMap<String, String>) -> Self {
+        Self {
+            texts,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns the text of `run`, including changes not applied yet.
+    pub fn get(&self, run: &str) -> Option<&str> {
+        self.texts.get(run).map(String::as_str)
+    }
+
+    pub fn set(&mut self, run: &str, text: impl Into<String>) -> Result<(), RiveTextError> {
+        let current = self
+            .texts
+            .get_mut(run)
+            .ok_or_else(|| RiveTextError::Unknown(run.to_string()))?;
+
+        *current = text.into();
+        self.pending.push(run.to_string());
+
+        Ok(())
+    }
+
+    /// Paths of all the text runs in the scene.
+    pub fn runs(&self) -> impl Iterator<Item = &str> {
+        self.texts.keys().map(String::as_str)
+    }
+}
+
+pub(crate) fn apply_text(
+    mut query: Query<
+        (
+            &mut RiveText,
+            Option<&RiveLinearAnimation>,
+            Option<&RiveStateMachine>,
+        ),
+        Changed<RiveText>,
+    >,
+) {
+    for (mut text, linear_animation, state_machine) in &mut query {
+        if text.pending.is_empty() {
+            continue;
+        }
+
+        let mut artboard = match (linear_animation, state_machine) {
+            (Some(linear_animation), None) => linear_animation.artboard(),
+            (None, Some(state_machine)) => state_machine.artboard(),
+            _ => continue,
+        };
+        let text = text.bypass_change_detection();
+
+        for run in text.pending.drain(..) {
+            if let Some(mut value_run) = find_run(&mut artboard, &run) {
+                value_run.set_text(&text.texts[&run]);
+            }
+        }
+    }
+}
+
+/// Keeps a text run in sync with a reflected `String` field, with the same
+/// paths as [`InputBinding`](crate::InputBinding).
+#[derive(Clone, Debug)]
+pub struct TextBinding {
+    path: String,
+    run: String,
+    entity: Option<Entity>,
+    reported: bool,
+}
+
+impl TextBinding {
+    pub fn new(path: impl Into<String>, run: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            run: run.into(),
+            entity: None,
+            reported: false,
+        }
+    }
+
+    /// Reads the component from `entity` instead of the scene's.
+    pub fn on(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+}
+
+/// Text runs of the entity's scene that are driven by reflected fields,
+/// synced every frame before the scene advances.
+#[derive(Clone, Component, Debug, Default)]
+pub struct RiveTextBindings(pub Vec<TextBinding>);
+
+impl RiveTextBindings {
+    pub fn with(mut self, binding: TextBinding) -> Self {
+        self.0.push(binding);
+        self
+    }
+}
+
+impl BindingSet for RiveTextBindings {
+    type Binding = TextBinding;
+
+    fn bindings_mut(&mut self) -> &mut [TextBinding] {
+        &mut self.0
+    }
+
+    fn path(binding: &TextBinding) -> &str {
+        &binding.path
+    }
+
+    fn reported(binding: &mut TextBinding) -> &mut bool {
+        &mut binding.reported
+    }
+}
+
+pub(crate) fn sync_text_bindings(world: &mut World) {
+    sync_bindings::<RiveTextBindings, RiveText, _>(
+        world,
+        |world, entity, binding| {
+            let current = world
+                .get::<RiveText>(entity)
+                .and_then(|rive_text| rive_text.get(&binding.run))
+                .ok_or_else(|| RiveTextError::Unknown(binding.run.clone()).to_string())?;
+            let text = read_field(
+                world,
+                binding.entity.unwrap_or(entity),
+                &binding.path,
+                |value| value.as_any().downcast_ref::<String>().cloned(),
+                "a String",
+            )?;
+
+            Ok((current != text).then(|| (binding.run.clone(), text)))
+        },
+        |entity, texts| {
+            let Some(mut rive_text) = entity.get_mut::<RiveText>() else {
+                return;
+            };
+
+            for (run, text) in texts {
+                // Runs were checked when the values were read.
+                let _ = rive_text.set(&run, text);
+            }
+        },
+    );
+}
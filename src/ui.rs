@@ -0,0 +1,86 @@
+use bevy::{prelude::*, render::render_resource::Extent3d, ui::FocusPolicy, window::PrimaryWindow};
+
+use crate::components::{new_target_image, StateMachine};
+
+/// Marks a scene that is displayed as a `bevy_ui` node.
+#[derive(Clone, Component, Copy, Debug, Default)]
+pub struct RiveUiNode;
+
+/// A `bevy_ui` node displaying a [`StateMachine`].
+///
+/// The target image is created by the plugin and kept the same size as the
+/// node's computed layout, in physical pixels. Pointer input is passed to the
+/// scene whenever the node's [`Interaction`] is not [`Interaction::None`].
+#[derive(Bundle, Clone, Debug)]
+pub struct RiveUiBundle {
+    pub state_machine: StateMachine,
+    pub node: NodeBundle,
+    pub ui_image: UiImage,
+    pub interaction: Interaction,
+    pub marker: RiveUiNode,
+    pub image: Handle<Image>,
+}
+
+impl Default for RiveUiBundle {
+    fn default() -> Self {
+        Self {
+            state_machine: StateMachine::default(),
+            node: NodeBundle {
+                // The background color tints the image.
+                background_color: Color::WHITE.into(),
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            ui_image: UiImage::default(),
+            interaction: Interaction::default(),
+            marker: RiveUiNode,
+            image: Handle::default(),
+        }
+    }
+}
+
+fn physical_size(node: &Node, scale_factor: f64, ui_scale: f64) -> UVec2 {
+    node.physical_size(scale_factor, ui_scale)
+        .round()
+        .as_uvec2()
+        .max(UVec2::ONE)
+}
+
+pub(crate) fn resize_ui_images(
+    mut query: Query<(&Node, &mut Handle<Image>, &mut UiImage), With<RiveUiNode>>,
+    mut image_assets: ResMut<Assets<Image>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<UiScale>>,
+) {
+    let scale_factor = windows.get_single().map_or(1.0, |w| w.scale_factor());
+    let ui_scale = ui_scale.map_or(1.0, |ui_scale| ui_scale.0);
+
+    for (node, mut image_handle, mut ui_image) in &mut query {
+        let size = physical_size(node, scale_factor, ui_scale);
+        let extent = Extent3d {
+            width: size.x,
+            height: size.y,
+            ..default()
+        };
+
+        // The default handle points to Bevy's shared placeholder image.
+        let current_size = (*image_handle != Handle::default())
+            .then(|| image_assets.get(&*image_handle))
+            .flatten()
+            .map(Image::size);
+
+        match current_size {
+            None => *image_handle = image_assets.add(new_target_image(size)),
+            Some(current_size) if current_size != size => {
+                if let Some(image) = image_assets.get_mut(&*image_handle) {
+                    image.resize(extent);
+                }
+            }
+            Some(_) => (),
+        }
+
+        if ui_image.texture != *image_handle {
+            ui_image.texture = image_handle.clone();
+        }
+    }
+}
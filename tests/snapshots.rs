@@ -0,0 +1,60 @@
+//! Golden-image tests of the bundled `.riv` files.
+//!
+//! Goldens live in `tests/goldens`. Run with `RIVE_UPDATE_SNAPSHOTS=1` to
+//! (re)write them after an intended change and review the PNGs before
+//! committing them.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rive_bevy::{snapshot::Snapshot, LinearAnimation, StateMachine};
+
+const FRAME: Duration = Duration::from_millis(16);
+
+#[test]
+fn marty() {
+    let mut snapshot = Snapshot::new("marty.riv", UVec2::splat(256), |riv| LinearAnimation {
+        riv,
+        ..default()
+    })
+    .unwrap();
+
+    snapshot.advance(Duration::ZERO);
+    snapshot.assert_matches("marty-0ms");
+
+    snapshot.advance_frames(30, FRAME);
+    snapshot.assert_matches("marty-480ms");
+}
+
+#[test]
+fn circle_fui() {
+    let mut snapshot = Snapshot::new("circle-fui.riv", UVec2::splat(256), |riv| StateMachine {
+        riv,
+        ..default()
+    })
+    .unwrap();
+
+    snapshot.advance(Duration::ZERO);
+    snapshot.assert_matches("circle-fui-0ms");
+
+    snapshot.advance_frames(30, FRAME);
+    snapshot.assert_matches("circle-fui-480ms");
+}
+
+#[test]
+fn shmup_bugs() {
+    for bug in 1..=3 {
+        let path = format!("shmup/bug_{bug}.riv");
+        let mut snapshot = Snapshot::new(path, UVec2::splat(128), |riv| StateMachine {
+            riv,
+            ..default()
+        })
+        .unwrap();
+
+        snapshot.advance(Duration::ZERO);
+        snapshot.assert_matches(&format!("bug_{bug}-0ms"));
+
+        snapshot.advance_frames(30, FRAME);
+        snapshot.assert_matches(&format!("bug_{bug}-480ms"));
+    }
+}